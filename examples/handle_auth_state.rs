@@ -1,14 +1,18 @@
 use rust_tdlib::types::AuthorizationState;
 use rust_tdlib::{
-    client::{Client, ClientState, SignalAuthStateHandler, Worker},
-    tdjson,
+    client::{Client, ClientState, SignalAuthStateHandler, TdlibLogging, Worker},
     types::TdlibParameters,
 };
 
 #[tokio::main]
 async fn main() {
-    tdjson::set_log_verbosity_level(1);
     env_logger::init();
+    TdlibLogging::builder()
+        .with_verbosity_level(1)
+        .with_forwarding()
+        .build()
+        .init()
+        .expect("can't configure TDLib logging");
 
     let db_dir = "tddb_auth";
 
@@ -39,6 +43,7 @@ async fn main() {
     let auth_handler = SignalAuthStateHandler::new(rx);
     let mut worker = Worker::builder()
         .with_auth_state_handler(auth_handler)
+        .with_auth_attempts(2)
         .build()
         .unwrap();
 
@@ -49,6 +54,15 @@ async fn main() {
     sx.send("".to_string()).await.unwrap(); // empty encryption key
     sx.send("invalid phone".to_string()).await.unwrap(); // wrong phone number: not a number
 
+    // TDLib rejects invalid phone number, so worker asks auth handler for it again.
+    sx.send(std::env::var("PHONE_NUMBER").unwrap())
+        .await
+        .unwrap();
+    // on this step tdlib usually waits for auth code, which sent by special telegram account.
+    // Both attempts fail, so error will be reported with `Worker::wait_auth_state_change`.
+    sx.send("incorrect empty code".to_string()).await.unwrap();
+    sx.send("another incorrect code".to_string()).await.unwrap();
+
     let client = worker
         .bind_client(client, None)
         .await
@@ -56,52 +70,35 @@ async fn main() {
 
     loop {
         match worker.wait_auth_state_change(&client).await {
-            Ok(res) => {
-                match res {
-                    Ok(state) => match state {
-                        ClientState::Opened => {
-                            log::info!("client authorized; can start interaction");
-                        }
-                        ClientState::Closed => {
-                            log::warn!("client closed, need to reauthorize it");
-                            break;
-                        }
-                        ClientState::Authorizing => {
-                            log::info!("client not authorized yet")
+            Ok(res) => match res {
+                Ok(state) => match state {
+                    ClientState::Opened => {
+                        log::info!("client authorized; can start interaction");
+                    }
+                    ClientState::Closed => {
+                        log::warn!("client closed, need to reauthorize it");
+                        break;
+                    }
+                    ClientState::Authorizing => {
+                        log::info!("client not authorized yet")
+                    }
+                },
+                Err((err, auth_state)) => {
+                    match &auth_state.authorization_state() {
+                        AuthorizationState::WaitCode(_) => {
+                            log::error!("received code error, need to handle it here: {}", err);
                         }
-                    },
-                    Err((err, auth_state)) => {
-                        match &auth_state.authorization_state() {
-                            AuthorizationState::WaitPhoneNumber(_) => {
-                                log::info!("received error when setup phone number: {}", err);
-                                // send correct phone number
-                                sx.send(std::env::var("PHONE_NUMBER").unwrap().to_string())
-                                    .await
-                                    .unwrap();
-                                // and handle auth state manually again
-                                worker
-                                    .handle_auth_state(auth_state.authorization_state(), &client)
-                                    .await
-                                    .expect("can't handle it");
-                                // on this step tdlib usually waits for auth code, which sent by special telegram account
-                                sx.send("incorrect empty code".to_string()).await.unwrap();
-                            }
-                            AuthorizationState::WaitCode(_) => {
-                                log::error!("received code error, need to handle it here");
-                                break;
-                            }
-                            _ => {
-                                log::error!(
-                                    "state: {:?}, error: {:?}",
-                                    auth_state.authorization_state(),
-                                    err
-                                );
-                                break;
-                            }
+                        _ => {
+                            log::error!(
+                                "state: {:?}, error: {:?}",
+                                auth_state.authorization_state(),
+                                err
+                            );
                         }
                     }
+                    break;
                 }
-            }
+            },
             Err(err) => {
                 log::error!("cannot wait for auth state changes: {}", err);
                 break;
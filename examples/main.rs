@@ -1,7 +1,6 @@
 use rust_tdlib::types::{GetUser, MessageContent, RTDUserBuilder, User, UserBuilder, UserFullInfo};
 use rust_tdlib::{
-    client::{Client, ClientState, Worker},
-    tdjson,
+    client::{Client, ClientState, TdlibLogging, Worker},
     types::{
         FormattedText, GetMe, InputMessageContent, InputMessageText, SearchPublicChat,
         SearchPublicChats, SendMessage, TdlibParameters, Update,
@@ -11,8 +10,14 @@ use std::borrow::Borrow;
 
 #[tokio::main]
 async fn main() {
-    tdjson::set_log_verbosity_level(1);
     env_logger::init();
+    TdlibLogging::builder()
+        .with_verbosity_level(1)
+        // TDLib log goes to env_logger instead of stderr
+        .with_forwarding()
+        .build()
+        .init()
+        .expect("can't configure TDLib logging");
     let (sender, mut receiver) = tokio::sync::mpsc::channel::<Box<Update>>(10000);
 
     let client1 = Client::builder()
@@ -56,22 +61,14 @@ async fn main() {
 
     let client1 = worker.bind_client(client1, None).await.unwrap();
 
-    loop {
-        if worker.wait_client_state(&client1).await.unwrap() == ClientState::Opened {
-            log::info!("client1 authorized");
-            break;
-        }
-    }
+    client1.authorized().await.unwrap();
+    log::info!("client1 authorized");
     let me1 = client1.get_me(GetMe::builder().build()).await.unwrap();
     log::info!("me 1: {:?}", me1);
 
     let client2 = worker.bind_client(client2, None).await.unwrap();
-    loop {
-        if worker.wait_client_state(&client2).await.unwrap() == ClientState::Opened {
-            log::info!("client2 authorized");
-            break;
-        }
-    }
+    client2.authorized().await.unwrap();
+    log::info!("client2 authorized");
     let me2 = client2.get_me(GetMe::builder().build()).await.unwrap();
     log::info!("me 2: {:?}", me2);
 
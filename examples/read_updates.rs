@@ -1,14 +1,18 @@
 use rust_tdlib::client::ClientState;
 use rust_tdlib::{
-    client::{Client, Worker},
-    tdjson,
+    client::{Client, TdlibLogging, Worker},
     types::{TdlibParameters, Update},
 };
 
 #[tokio::main]
 async fn main() {
-    tdjson::set_log_verbosity_level(1);
     env_logger::init();
+    TdlibLogging::builder()
+        .with_verbosity_level(1)
+        .with_forwarding()
+        .build()
+        .init()
+        .expect("can't configure TDLib logging");
     let tdlib_parameters = TdlibParameters::builder()
         .database_directory("tddb")
         .use_test_dc(false)
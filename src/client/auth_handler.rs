@@ -1,5 +1,6 @@
+use crate::errors::Error;
 use crate::types::{
-    AuthorizationStateWaitCode, AuthorizationStateWaitEncryptionKey,
+    AuthorizationState, AuthorizationStateWaitCode, AuthorizationStateWaitEncryptionKey,
     AuthorizationStateWaitOtherDeviceConfirmation, AuthorizationStateWaitPassword,
     AuthorizationStateWaitPhoneNumber, AuthorizationStateWaitRegistration,
 };
@@ -8,6 +9,16 @@ use std::io;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+/// Action chosen by [AuthStateHandler](crate::client::auth_handler::AuthStateHandler) for [WaitPassword](crate::types::AuthorizationStateWaitPassword) state.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaitPasswordAction {
+    /// Check the password
+    Password(String),
+    /// Password is forgotten: recover it with code sent to the recovery email address.
+    /// Available only if [has_recovery_email_address](crate::types::AuthorizationStateWaitPassword::has_recovery_email_address) is true.
+    Recover,
+}
+
 /// `AuthStateHandler` trait provides methods that returns data, required for authentication
 /// It allows you to handle particular "auth states", such as [WaitPassword](crate::types::AuthorizationStateWaitPassword), [WaitPhoneNumber](crate::types::AuthorizationStateWaitPhoneNumber) and so on.
 #[async_trait]
@@ -31,6 +42,33 @@ pub trait AuthStateHandler {
     ) -> String;
     /// Returns password
     async fn handle_wait_password(&self, wait_password: &AuthorizationStateWaitPassword) -> String;
+    /// Returns password or chooses password recovery.
+    /// Default implementation returns password from [handle_wait_password](crate::client::auth_handler::AuthStateHandler::handle_wait_password).
+    async fn handle_wait_password_action(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> WaitPasswordAction {
+        WaitPasswordAction::Password(self.handle_wait_password(wait_password).await)
+    }
+    /// Returns password recovery code, sent to the email address matching [recovery_email_address_pattern](crate::types::AuthorizationStateWaitPassword::recovery_email_address_pattern).
+    /// Called after [WaitPasswordAction::Recover](crate::client::auth_handler::WaitPasswordAction::Recover) is chosen.
+    /// Default implementation returns empty code, which TDLib rejects.
+    async fn handle_password_recovery_code(
+        &self,
+        _wait_password: &AuthorizationStateWaitPassword,
+    ) -> String {
+        log::warn!("password recovery isn't supported by the auth state handler");
+        String::new()
+    }
+    /// Returns new password and its hint, which will be set after password recovery. Empty password removes it.
+    /// Default implementation removes the password.
+    async fn handle_new_password(
+        &self,
+        _wait_password: &AuthorizationStateWaitPassword,
+    ) -> (String, String) {
+        log::warn!("new password isn't provided by the auth state handler");
+        (String::new(), String::new())
+    }
     /// Returns phone number
     async fn handle_wait_phone_number(
         &self,
@@ -41,10 +79,23 @@ pub trait AuthStateHandler {
         &self,
         wait_registration: &AuthorizationStateWaitRegistration,
     ) -> (String, String);
+    /// Called when TDLib rejects data returned for `auth_state`; `attempt` is the number of the failed attempt, starting from 1.
+    /// Return `false` to stop retrying and report the error. The result is ignored when it's the last attempt
+    /// or the error can't be fixed by another input: then the error is reported anyway.
+    async fn handle_auth_error(
+        &self,
+        auth_state: &AuthorizationState,
+        error: &Error,
+        attempt: usize,
+    ) -> bool {
+        log::warn!("attempt {} for {:?} failed: {}", attempt, auth_state, error);
+        true
+    }
 }
 
 /// Provides minimal implementation of `AuthStateHandler`.
 /// All required methods wait (synchronously) for stdin input
+/// If recovery email address is set, empty password starts password recovery.
 #[derive(Debug, Clone)]
 pub struct ConsoleAuthStateHandler;
 
@@ -91,6 +142,42 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
         ConsoleAuthStateHandler::wait_input()
     }
 
+    async fn handle_wait_password_action(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> WaitPasswordAction {
+        if !wait_password.has_recovery_email_address() {
+            return WaitPasswordAction::Password(self.handle_wait_password(wait_password).await);
+        }
+        println!(
+            "waiting for password (hint: {}), leave it empty to recover password with {}",
+            wait_password.password_hint(),
+            wait_password.recovery_email_address_pattern()
+        );
+        password_action(ConsoleAuthStateHandler::wait_input())
+    }
+
+    async fn handle_password_recovery_code(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> String {
+        println!(
+            "waiting for recovery code sent to {}",
+            wait_password.recovery_email_address_pattern()
+        );
+        ConsoleAuthStateHandler::wait_input()
+    }
+
+    async fn handle_new_password(
+        &self,
+        _wait_password: &AuthorizationStateWaitPassword,
+    ) -> (String, String) {
+        println!("waiting for new password");
+        let password = ConsoleAuthStateHandler::wait_input();
+        println!("waiting for new password hint");
+        (password, ConsoleAuthStateHandler::wait_input())
+    }
+
     async fn handle_wait_phone_number(
         &self,
         _wait_phone_number: &AuthorizationStateWaitPhoneNumber,
@@ -111,9 +198,20 @@ impl AuthStateHandler for ConsoleAuthStateHandler {
             }
         }
     }
+
+    async fn handle_auth_error(
+        &self,
+        _auth_state: &AuthorizationState,
+        error: &Error,
+        attempt: usize,
+    ) -> bool {
+        println!("attempt {} failed: {}", attempt, error);
+        true
+    }
 }
 
 /// All required methods wait for data sent by [Sender](tokio::sync::mpsc::Sender).
+/// If recovery email address is set, empty password starts password recovery: then recovery code, new password and its hint have to be sent.
 #[derive(Debug, Clone)]
 pub struct SignalAuthStateHandler {
     rec: Arc<Mutex<tokio::sync::mpsc::Receiver<String>>>,
@@ -151,6 +249,29 @@ impl AuthStateHandler for SignalAuthStateHandler {
         self.wait_signal().await
     }
 
+    async fn handle_wait_password_action(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> WaitPasswordAction {
+        if !wait_password.has_recovery_email_address() {
+            return WaitPasswordAction::Password(self.handle_wait_password(wait_password).await);
+        }
+        log::info!("waiting for password, empty one starts password recovery");
+        password_action(self.wait_signal().await)
+    }
+
+    async fn handle_password_recovery_code(&self, _: &AuthorizationStateWaitPassword) -> String {
+        log::info!("waiting for password recovery code");
+        self.wait_signal().await
+    }
+
+    async fn handle_new_password(&self, _: &AuthorizationStateWaitPassword) -> (String, String) {
+        log::info!("waiting for new password");
+        let password = self.wait_signal().await;
+        log::info!("waiting for new password hint");
+        (password, self.wait_signal().await)
+    }
+
     async fn handle_wait_phone_number(&self, _: &AuthorizationStateWaitPhoneNumber) -> String {
         log::info!("waiting for phone number");
         self.wait_signal().await
@@ -170,6 +291,14 @@ impl AuthStateHandler for SignalAuthStateHandler {
     }
 }
 
+// Empty password can't be valid, so it's used to choose password recovery.
+fn password_action(input: String) -> WaitPasswordAction {
+    match input.is_empty() {
+        true => WaitPasswordAction::Recover,
+        false => WaitPasswordAction::Password(input),
+    }
+}
+
 fn split_string(input: String, sep: char) -> Option<(String, String)> {
     let found: Vec<&str> = input.splitn(2, |c| c == sep).collect();
     if let 2 = found.len() {
@@ -0,0 +1,235 @@
+use super::tdlib_client::TdLibClient;
+use super::{seconds, Client};
+use crate::errors::{Error, Result};
+use crate::types::RFunction;
+use futures::stream::{self, StreamExt};
+use serde::de::DeserializeOwned;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const FLOOD_WAIT_CODES: [i32; 2] = [420, 429];
+const FLOOD_WAIT_PREFIXES: [&str; 2] = ["retry after ", "FLOOD_WAIT_"];
+
+/// Returns how long TDLib asks to wait before the next request, if the error is a flood wait error,
+/// such as `Too Many Requests: retry after 10` or `FLOOD_WAIT_10`.
+pub fn flood_wait_duration(error: &Error) -> Option<Duration> {
+    let error = match error {
+        Error::TDLibError(error) if FLOOD_WAIT_CODES.contains(&error.code()) => error,
+        _ => return None,
+    };
+    let message = error.message();
+    FLOOD_WAIT_PREFIXES.iter().find_map(|prefix| {
+        let start = message.find(prefix)? + prefix.len();
+        let seconds: String = message[start..]
+            .chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect();
+        seconds.parse().ok().map(Duration::from_secs)
+    })
+}
+
+/// Settings of [Client::batch_with_options](crate::client::Client::batch_with_options).
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    concurrency: usize,
+    flood_wait_retries: usize,
+    max_flood_wait: Duration,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 10,
+            flood_wait_retries: 3,
+            max_flood_wait: Duration::from_secs(300),
+        }
+    }
+}
+
+impl BatchOptions {
+    pub fn builder() -> BatchOptionsBuilder {
+        BatchOptionsBuilder::default()
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    pub fn flood_wait_retries(&self) -> usize {
+        self.flood_wait_retries
+    }
+
+    pub fn max_flood_wait(&self) -> Duration {
+        self.max_flood_wait
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct BatchOptionsBuilder {
+    inner: BatchOptions,
+}
+
+impl BatchOptionsBuilder {
+    /// Maximum number of requests sent to TDLib simultaneously. Default is 10. Values below 1 are treated as 1.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.inner.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// How many times request will be retried after flood wait error. Default is 3.
+    pub fn with_flood_wait_retries(mut self, flood_wait_retries: usize) -> Self {
+        self.inner.flood_wait_retries = flood_wait_retries;
+        self
+    }
+
+    /// Flood wait errors with greater wait time, in seconds, are returned without retry. Default is 300.
+    /// Negative wait time is treated as 0; NaN is ignored.
+    pub fn with_max_flood_wait(mut self, max_flood_wait: f64) -> Self {
+        if let Some(max_flood_wait) = seconds::duration(max_flood_wait, 0.) {
+            self.inner.max_flood_wait = max_flood_wait;
+        }
+        self
+    }
+
+    pub fn build(self) -> BatchOptions {
+        self.inner
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Sends many requests of one type with default [BatchOptions](crate::client::batch::BatchOptions).
+    /// Results are returned in the order of requests.
+    /// ```no_run
+    /// # use rust_tdlib::{client::Client, errors::Result, types::{GetUser, User}};
+    /// # async fn run(client: Client<rust_tdlib::client::tdlib_client::TdJson>) {
+    /// let requests = (1..100).map(|user_id| GetUser::builder().user_id(user_id).build());
+    /// let users: Vec<Result<User>> = client.batch(requests).await;
+    /// # }
+    /// ```
+    pub async fn batch<T, Q, I>(&self, requests: I) -> Vec<Result<Q>>
+    where
+        T: RFunction + AsRef<T>,
+        Q: DeserializeOwned,
+        I: IntoIterator<Item = T>,
+    {
+        self.batch_with_options(requests, &BatchOptions::default())
+            .await
+    }
+
+    /// Sends many requests of one type, at most [concurrency](crate::client::batch::BatchOptions::concurrency) at a time.
+    /// Results are returned in the order of requests.
+    /// When TDLib responds with flood wait error, all requests of the batch are paused for requested time and failed request is retried.
+    pub async fn batch_with_options<T, Q, I>(
+        &self,
+        requests: I,
+        options: &BatchOptions,
+    ) -> Vec<Result<Q>>
+    where
+        T: RFunction + AsRef<T>,
+        Q: DeserializeOwned,
+        I: IntoIterator<Item = T>,
+    {
+        let paused_until = Mutex::new(None);
+        stream::iter(requests)
+            .map(|request| self.batch_request(request, options, &paused_until))
+            .buffered(options.concurrency)
+            .collect()
+            .await
+    }
+
+    async fn batch_request<T, Q>(
+        &self,
+        request: T,
+        options: &BatchOptions,
+        paused_until: &Mutex<Option<Instant>>,
+    ) -> Result<Q>
+    where
+        T: RFunction + AsRef<T>,
+        Q: DeserializeOwned,
+    {
+        let mut retries = 0;
+        loop {
+            self.wait_flood_pause(paused_until).await;
+            let err = match self.make_request::<T, _, Q>(&request).await {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+            let wait = match flood_wait_duration(&err) {
+                Some(wait)
+                    if retries < options.flood_wait_retries
+                        && wait <= options.max_flood_wait
+                        && self.executor().is_some() =>
+                {
+                    wait
+                }
+                _ => return Err(err),
+            };
+            retries += 1;
+            log::warn!("flood wait for {:?}, retry {}", wait, retries);
+            let until = Instant::now() + wait;
+            let mut paused_until = paused_until.lock().unwrap();
+            if paused_until.is_none_or(|paused_until| paused_until < until) {
+                *paused_until = Some(until);
+            }
+        }
+    }
+
+    async fn wait_flood_pause(&self, paused_until: &Mutex<Option<Instant>>) {
+        let executor = match self.executor() {
+            None => return,
+            Some(executor) => executor,
+        };
+        loop {
+            let until = *paused_until.lock().unwrap();
+            match until.and_then(|until| until.checked_duration_since(Instant::now())) {
+                Some(wait) if !wait.is_zero() => executor.sleep(wait).await,
+                _ => return,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{flood_wait_duration, BatchOptions};
+    use crate::errors::Error;
+    use crate::types::Error as TDLibError;
+    use std::time::Duration;
+
+    fn tdlib_error(code: i32, message: &str) -> Error {
+        Error::TDLibError(TDLibError::builder().code(code).message(message).build())
+    }
+
+    #[test]
+    fn parse_flood_wait() {
+        assert_eq!(
+            flood_wait_duration(&tdlib_error(429, "Too Many Requests: retry after 15")),
+            Some(Duration::from_secs(15))
+        );
+        assert_eq!(
+            flood_wait_duration(&tdlib_error(420, "FLOOD_WAIT_7")),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(
+            flood_wait_duration(&tdlib_error(400, "retry after 15")),
+            None
+        );
+        assert_eq!(
+            flood_wait_duration(&Error::BadRequest("retry after 15")),
+            None
+        );
+    }
+
+    #[test]
+    fn max_flood_wait_validated() {
+        let options = BatchOptions::builder().with_max_flood_wait(-1.).build();
+        assert_eq!(options.max_flood_wait(), Duration::ZERO);
+        let options = BatchOptions::builder()
+            .with_max_flood_wait(f64::NAN)
+            .build();
+        assert_eq!(options.max_flood_wait(), Duration::from_secs(300));
+    }
+}
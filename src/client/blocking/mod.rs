@@ -0,0 +1,366 @@
+//! Blocking facade over [Worker](crate::client::worker::Worker) and [Client](crate::client::Client) for applications which don't use tokio.
+//! ```no_run
+//! use rust_tdlib::client::blocking::BlockingClient;
+//! use rust_tdlib::types::{GetMe, TdlibParameters, Update};
+//! let tdlib_parameters = TdlibParameters::builder()
+//!     .api_id(env!("API_ID").parse::<i32>().unwrap())
+//!     .api_hash(env!("API_HASH"))
+//!     .build();
+//! let client = BlockingClient::builder()
+//!     .with_tdlib_parameters(tdlib_parameters)
+//!     .with_updates_channel(100)
+//!     .build()
+//!     .unwrap();
+//! client.authorized().unwrap();
+//! let me = client.get_me(GetMe::builder().build()).unwrap();
+//! println!("{:?}", me);
+//! for update in client.updates().unwrap() {
+//!     if let Update::NewMessage(message) = update.as_ref() {
+//!         println!("{:?}", message);
+//!     }
+//! }
+//! ```
+#[doc(hidden)]
+mod api;
+
+use super::auth_handler::{AuthStateHandler, ConsoleAuthStateHandler};
+use super::tdlib_client::{TdJson, TdLibClient};
+use super::worker::{Worker, WorkerBuilder};
+use super::{Client, ClientBuilder, ClientState};
+use crate::errors::{Error, Result};
+use crate::types::{AddProxy, TdlibParameters, Update};
+use std::future::Future;
+use std::sync::{Mutex, MutexGuard, TryLockError};
+use std::time::Duration;
+use tokio::runtime::{Builder, Runtime};
+use tokio::sync::{mpsc, watch};
+
+const UPDATES_CHANNEL_NOT_SPECIFIED: Error = Error::BadRequest(
+    "updates channel not specified, need to call `BlockingClientBuilder::with_updates_channel(...)`",
+);
+const UPDATES_IN_USE: Error =
+    Error::BadRequest("updates are already received with another `Updates` iterator");
+const INVALID_TIMEOUT: Error = Error::BadRequest("timeout must be a finite number of seconds");
+
+/// Owns a small tokio runtime, the [Worker](crate::client::worker::Worker) and the bound [Client](crate::client::Client).
+/// All TDLib methods, such as [send_message](crate::client::blocking::BlockingClient::send_message) or [get_chat](crate::client::blocking::BlockingClient::get_chat), are available as blocking calls.
+/// Methods must not be called from within an asynchronous context.
+#[derive(Debug)]
+pub struct BlockingClient<A = ConsoleAuthStateHandler, R = TdJson>
+where
+    A: AuthStateHandler + Send + Sync + 'static,
+    R: TdLibClient + Send + Sync + Clone + 'static,
+{
+    runtime: Runtime,
+    worker: Worker<A, R>,
+    // becomes `true` when the worker task is finished
+    stopped: watch::Receiver<bool>,
+    client: Client<R>,
+    updates: Option<Mutex<mpsc::Receiver<Box<Update>>>>,
+}
+
+impl BlockingClient<ConsoleAuthStateHandler, TdJson> {
+    pub fn builder() -> BlockingClientBuilder<ConsoleAuthStateHandler, TdJson> {
+        BlockingClientBuilder::default()
+    }
+}
+
+impl<A, R> BlockingClient<A, R>
+where
+    A: AuthStateHandler + Send + Sync + 'static,
+    R: TdLibClient + Send + Sync + Clone + 'static,
+{
+    /// Returns underlying asynchronous client.
+    pub fn client(&self) -> &Client<R> {
+        &self.client
+    }
+
+    /// Returns underlying worker.
+    pub fn worker(&self) -> &Worker<A, R> {
+        &self.worker
+    }
+
+    /// Runs any future, for example one returned by the asynchronous client, on the internal runtime.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Blocks until the client is authorized, see [Client::authorized](crate::client::Client::authorized).
+    pub fn authorized(&self) -> Result<()> {
+        self.block_on(self.client.authorized())
+    }
+
+    /// Returns blocking iterator over received updates. Iterator ends when the worker stops and all received updates are returned.
+    /// Returns error if [with_updates_channel](crate::client::blocking::BlockingClientBuilder::with_updates_channel) was not called
+    /// or another iterator is alive.
+    pub fn updates(&self) -> Result<Updates<'_>> {
+        Ok(Updates {
+            runtime: &self.runtime,
+            receiver: self.updates_receiver()?,
+            stopped: self.stopped.clone(),
+        })
+    }
+
+    /// Waits for the next update at most `timeout` seconds; negative timeout is treated as 0.
+    /// Returns `Ok(None)` if there is no update or the worker stopped.
+    /// Returns error if `timeout` is NaN or infinite, or an [Updates](crate::client::blocking::Updates) iterator is alive.
+    pub fn recv_update_timeout(&self, timeout: f64) -> Result<Option<Box<Update>>> {
+        let timeout = timeout_duration(timeout)?;
+        let mut updates = self.updates()?;
+        let update = self.block_on(tokio::time::timeout(timeout, updates.recv()));
+        Ok(update.ok().flatten())
+    }
+
+    /// Closes the client, waits until TDLib closes it and stops the worker.
+    pub fn shutdown(self) -> Result<()> {
+        let Self {
+            runtime,
+            worker,
+            mut stopped,
+            client,
+            ..
+        } = self;
+        runtime.block_on(async move {
+            let mut watcher = client.state_watcher();
+            client.stop().await?;
+            while watcher.borrow_and_update().client_state() != Some(ClientState::Closed) {
+                if watcher.changed().await.is_err() {
+                    break;
+                }
+            }
+            worker.stop();
+            // error means that the runtime is shutting down
+            let _ = stopped.wait_for(|stopped| *stopped).await;
+            Ok(())
+        })
+    }
+
+    fn updates_receiver(&self) -> Result<MutexGuard<'_, mpsc::Receiver<Box<Update>>>> {
+        let receiver = self.updates.as_ref().ok_or(UPDATES_CHANNEL_NOT_SPECIFIED)?;
+        lock_updates(receiver)
+    }
+}
+
+// The receiver is locked for the whole life of an iterator, so waiting for the lock on the same thread would never end.
+fn lock_updates<T>(receiver: &Mutex<T>) -> Result<MutexGuard<'_, T>> {
+    match receiver.try_lock() {
+        Ok(receiver) => Ok(receiver),
+        // the receiver can't be left in inconsistent state
+        Err(TryLockError::Poisoned(poisoned)) => Ok(poisoned.into_inner()),
+        Err(TryLockError::WouldBlock) => Err(UPDATES_IN_USE),
+    }
+}
+
+fn timeout_duration(timeout: f64) -> Result<Duration> {
+    if !timeout.is_finite() {
+        return Err(INVALID_TIMEOUT);
+    }
+    Ok(Duration::from_secs_f64(timeout.max(0.)))
+}
+
+/// Blocking iterator over updates, see [BlockingClient::updates](crate::client::blocking::BlockingClient::updates).
+#[derive(Debug)]
+pub struct Updates<'a> {
+    runtime: &'a Runtime,
+    receiver: MutexGuard<'a, mpsc::Receiver<Box<Update>>>,
+    stopped: watch::Receiver<bool>,
+}
+
+impl Updates<'_> {
+    // The client keeps updates sender, so the channel isn't closed when the worker stops.
+    async fn recv(&mut self) -> Option<Box<Update>> {
+        tokio::select! {
+            biased;
+            update = self.receiver.recv() => update,
+            _ = self.stopped.wait_for(|stopped| *stopped) => self.receiver.try_recv().ok(),
+        }
+    }
+}
+
+impl Iterator for Updates<'_> {
+    type Item = Box<Update>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.recv())
+    }
+}
+
+#[derive(Debug)]
+pub struct BlockingClientBuilder<A, R>
+where
+    A: AuthStateHandler + Send + Sync + 'static,
+    R: TdLibClient + Send + Sync + Clone + 'static,
+{
+    worker_builder: WorkerBuilder<A, R>,
+    client_builder: ClientBuilder<R>,
+    proxy: Option<AddProxy>,
+    updates_channel_size: Option<usize>,
+    worker_threads: usize,
+}
+
+impl Default for BlockingClientBuilder<ConsoleAuthStateHandler, TdJson> {
+    fn default() -> Self {
+        Self {
+            worker_builder: WorkerBuilder::default(),
+            client_builder: ClientBuilder::default(),
+            proxy: None,
+            updates_channel_size: None,
+            worker_threads: 1,
+        }
+    }
+}
+
+impl<A, R> BlockingClientBuilder<A, R>
+where
+    A: AuthStateHandler + Send + Sync + 'static,
+    R: TdLibClient + Send + Sync + Clone + 'static,
+{
+    /// [WorkerBuilder](crate::client::worker::WorkerBuilder) with custom timeouts or auth state handler.
+    pub fn with_worker_builder<N>(
+        self,
+        worker_builder: WorkerBuilder<N, R>,
+    ) -> BlockingClientBuilder<N, R>
+    where
+        N: AuthStateHandler + Send + Sync + 'static,
+    {
+        BlockingClientBuilder {
+            worker_builder,
+            client_builder: self.client_builder,
+            proxy: self.proxy,
+            updates_channel_size: self.updates_channel_size,
+            worker_threads: self.worker_threads,
+        }
+    }
+
+    /// [ClientBuilder](crate::client::ClientBuilder) of the client. Its updates sender will be replaced if [with_updates_channel](crate::client::blocking::BlockingClientBuilder::with_updates_channel) called.
+    pub fn with_client_builder(mut self, client_builder: ClientBuilder<R>) -> Self {
+        self.client_builder = client_builder;
+        self
+    }
+
+    /// Base parameters for your TDlib instance.
+    pub fn with_tdlib_parameters(mut self, tdlib_parameters: TdlibParameters) -> Self {
+        self.client_builder = self.client_builder.with_tdlib_parameters(tdlib_parameters);
+        self
+    }
+
+    /// Proxy to use, see [Worker::bind_client](crate::client::worker::Worker::bind_client).
+    pub fn with_proxy(mut self, proxy: AddProxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// If you want to read updates with [BlockingClient::updates](crate::client::blocking::BlockingClient::updates),
+    /// you have to specify positive size of the updates channel.
+    pub fn with_updates_channel(mut self, channel_size: usize) -> Self {
+        self.updates_channel_size = Some(channel_size);
+        self
+    }
+
+    /// Number of threads of the internal runtime. Default is 1.
+    pub fn with_worker_threads(mut self, worker_threads: usize) -> Self {
+        self.worker_threads = worker_threads.max(1);
+        self
+    }
+
+    /// Starts the worker and binds the client. Authorization continues in background, see [BlockingClient::authorized](crate::client::blocking::BlockingClient::authorized).
+    pub fn build(self) -> Result<BlockingClient<A, R>> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(self.worker_threads)
+            .thread_name("rust-tdlib-blocking")
+            .enable_all()
+            .build()?;
+
+        let (client_builder, updates) = match self.updates_channel_size {
+            None => (self.client_builder, None),
+            Some(size) => {
+                let (sx, rx) = mpsc::channel(size);
+                (
+                    self.client_builder.with_updates_sender(sx),
+                    Some(Mutex::new(rx)),
+                )
+            }
+        };
+        let client = client_builder.build()?;
+        let mut worker = self.worker_builder.build()?;
+        let proxy = self.proxy;
+
+        let (stop, stopped) = watch::channel(false);
+        let client = runtime.block_on(async {
+            let worker_handle = worker.start();
+            tokio::spawn(async move {
+                let _ = worker_handle.await;
+                stop.send_replace(true);
+            });
+            worker.bind_client(client, proxy).await
+        });
+        let client = match client {
+            Ok(client) => client,
+            Err(err) => {
+                worker.stop();
+                return Err(err);
+            }
+        };
+
+        Ok(BlockingClient {
+            runtime,
+            worker,
+            stopped,
+            client,
+            updates,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lock_updates, timeout_duration, Updates};
+    use crate::types::{Update, UpdateConnectionState};
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use tokio::runtime::Builder;
+    use tokio::sync::{mpsc, watch};
+
+    #[test]
+    fn updates_end_when_worker_stops() {
+        let runtime = Builder::new_current_thread().enable_all().build().unwrap();
+        // sender is alive, as it's kept by the client
+        let (sender, receiver) = mpsc::channel(10);
+        let receiver = Mutex::new(receiver);
+        let (stop, stopped) = watch::channel(false);
+        for _ in 0..2 {
+            let update = Update::ConnectionState(UpdateConnectionState::default());
+            sender.try_send(Box::new(update)).unwrap();
+        }
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            stop.send_replace(true);
+        });
+
+        let updates = Updates {
+            runtime: &runtime,
+            receiver: receiver.lock().unwrap(),
+            stopped,
+        };
+        assert_eq!(updates.count(), 2);
+        handle.join().unwrap();
+        drop(sender);
+    }
+
+    #[test]
+    fn updates_locked_once() {
+        let receiver = Mutex::new(());
+        let updates = lock_updates(&receiver).unwrap();
+        assert!(lock_updates(&receiver).is_err());
+        drop(updates);
+        assert!(lock_updates(&receiver).is_ok());
+    }
+
+    #[test]
+    fn recv_timeout() {
+        assert_eq!(timeout_duration(0.5).unwrap(), Duration::from_millis(500));
+        assert_eq!(timeout_duration(-1.).unwrap(), Duration::ZERO);
+        assert!(timeout_duration(f64::NAN).is_err());
+        assert!(timeout_duration(f64::INFINITY).is_err());
+    }
+}
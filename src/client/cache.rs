@@ -0,0 +1,437 @@
+use super::seconds;
+use crate::errors::{Error, Result};
+use futures::channel::oneshot;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Longer TTL could overflow expiration time of entries.
+const MAX_TTL: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+// Object which cached response depends on. Cached responses are dropped when TDLib sends update of the object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tag {
+    User(i64),
+    UserFullInfo(i64),
+    Chat(i64),
+    BasicGroup(i64),
+    BasicGroupFullInfo(i64),
+    Supergroup(i64),
+    SupergroupFullInfo(i64),
+    SecretChat(i64),
+    File(i64),
+    Message(i64, i64),
+}
+
+fn field(value: &Value, path: &[&str]) -> Option<i64> {
+    path.iter()
+        .try_fold(value, |value, key| value.get(key))?
+        .as_i64()
+}
+
+// Returns tag of cacheable request; other requests are sent as is.
+fn request_tag(request: &Value) -> Option<Tag> {
+    match request.get("@type")?.as_str()? {
+        "getUser" => Some(Tag::User(field(request, &["user_id"])?)),
+        "getUserFullInfo" => Some(Tag::UserFullInfo(field(request, &["user_id"])?)),
+        "getChat" => Some(Tag::Chat(field(request, &["chat_id"])?)),
+        "getBasicGroup" => Some(Tag::BasicGroup(field(request, &["basic_group_id"])?)),
+        "getBasicGroupFullInfo" => Some(Tag::BasicGroupFullInfo(field(
+            request,
+            &["basic_group_id"],
+        )?)),
+        "getSupergroup" => Some(Tag::Supergroup(field(request, &["supergroup_id"])?)),
+        "getSupergroupFullInfo" => {
+            Some(Tag::SupergroupFullInfo(field(request, &["supergroup_id"])?))
+        }
+        "getSecretChat" => Some(Tag::SecretChat(field(request, &["secret_chat_id"])?)),
+        "getFile" => Some(Tag::File(field(request, &["file_id"])?)),
+        "getMessage" => Some(Tag::Message(
+            field(request, &["chat_id"])?,
+            field(request, &["message_id"])?,
+        )),
+        _ => None,
+    }
+}
+
+// Returns tags of objects changed by the update.
+fn update_tags(update: &Value) -> Vec<Tag> {
+    let type_ = match update.get("@type").and_then(|t| t.as_str()) {
+        None => return vec![],
+        Some(type_) => type_,
+    };
+    let tag = match type_ {
+        "updateUser" => field(update, &["user", "id"]).map(Tag::User),
+        "updateUserStatus" => field(update, &["user_id"]).map(Tag::User),
+        "updateUserFullInfo" => field(update, &["user_id"]).map(Tag::UserFullInfo),
+        "updateBasicGroup" => field(update, &["basic_group", "id"]).map(Tag::BasicGroup),
+        "updateBasicGroupFullInfo" => {
+            field(update, &["basic_group_id"]).map(Tag::BasicGroupFullInfo)
+        }
+        "updateSupergroup" => field(update, &["supergroup", "id"]).map(Tag::Supergroup),
+        "updateSupergroupFullInfo" => {
+            field(update, &["supergroup_id"]).map(Tag::SupergroupFullInfo)
+        }
+        "updateSecretChat" => field(update, &["secret_chat", "id"]).map(Tag::SecretChat),
+        "updateNewChat" => field(update, &["chat", "id"]).map(Tag::Chat),
+        "updateFile" => field(update, &["file", "id"]).map(Tag::File),
+        "updateDeleteMessages" => {
+            let chat_id = match field(update, &["chat_id"]) {
+                None => return vec![],
+                Some(chat_id) => chat_id,
+            };
+            return update
+                .get("message_ids")
+                .and_then(|ids| ids.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|id| id.as_i64())
+                .map(|message_id| Tag::Message(chat_id, message_id))
+                .collect();
+        }
+        "updateMessageSendSucceeded" => field(update, &["message", "chat_id"])
+            .zip(field(update, &["old_message_id"]))
+            .map(|(chat_id, message_id)| Tag::Message(chat_id, message_id)),
+        _ if type_.starts_with("updateChat") => field(update, &["chat_id"]).map(Tag::Chat),
+        _ if type_.starts_with("updateMessage") => field(update, &["chat_id"])
+            .zip(field(update, &["message_id"]))
+            .map(|(chat_id, message_id)| Tag::Message(chat_id, message_id)),
+        _ => None,
+    };
+    tag.into_iter().collect()
+}
+
+// Errors can't be cloned, so followers of the same request receive their copies.
+fn copy_error(error: &Error) -> Error {
+    match error {
+        Error::TDLibError(err) => Error::TDLibError(err.clone()),
+        err => Error::RawStr(err.to_string()),
+    }
+}
+
+type Outcome = std::result::Result<Value, Error>;
+
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    tag: Tag,
+    expires_at: Instant,
+}
+
+#[derive(Debug)]
+struct InFlight {
+    tag: Tag,
+    stale: bool,
+    followers: Vec<oneshot::Sender<Outcome>>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<String, Entry>,
+    in_flight: HashMap<String, InFlight>,
+}
+
+#[derive(Debug, Clone)]
+struct RequestCacheSettings {
+    ttl: Duration,
+    max_entries: usize,
+}
+
+/// Opt-in layer which merges identical concurrent read-only requests into one TDLib call
+/// and keeps their responses for a short time.
+///
+/// Cached requests: [GetUser](crate::types::GetUser), [GetUserFullInfo](crate::types::GetUserFullInfo), [GetChat](crate::types::GetChat),
+/// [GetBasicGroup](crate::types::GetBasicGroup), [GetBasicGroupFullInfo](crate::types::GetBasicGroupFullInfo), [GetSupergroup](crate::types::GetSupergroup),
+/// [GetSupergroupFullInfo](crate::types::GetSupergroupFullInfo), [GetSecretChat](crate::types::GetSecretChat), [GetFile](crate::types::GetFile) and [GetMessage](crate::types::GetMessage).
+/// Cached responses are invalidated by updates of the same object, such as [UpdateUser](crate::types::UpdateUser) or [UpdateChatTitle](crate::types::UpdateChatTitle).
+/// Use [ClientBuilder::with_request_cache](crate::client::ClientBuilder::with_request_cache) to enable it.
+#[derive(Debug, Clone)]
+pub struct RequestCache {
+    settings: Arc<RequestCacheSettings>,
+    state: Arc<Mutex<CacheState>>,
+}
+
+enum Lookup {
+    Hit(Value),
+    Follow(oneshot::Receiver<Outcome>),
+    Lead,
+}
+
+// Removes in-flight request if leader's future dropped before completion, so followers send their own requests.
+struct LeaderGuard<'a> {
+    cache: &'a RequestCache,
+    key: Option<String>,
+}
+
+impl Drop for LeaderGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.cache.state.lock().unwrap().in_flight.remove(&key);
+        }
+    }
+}
+
+impl RequestCache {
+    pub fn builder() -> RequestCacheBuilder {
+        RequestCacheBuilder::default()
+    }
+
+    /// Number of cached responses, including expired ones.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops all cached responses.
+    pub fn clear(&self) {
+        self.state.lock().unwrap().entries.clear();
+    }
+
+    // Returns cache key of the request if it's cacheable.
+    pub(crate) fn key(&self, request: &Value) -> Option<String> {
+        request_tag(request)?;
+        let mut request = request.clone();
+        if let Some(request) = request.as_object_mut() {
+            request.remove("@extra");
+        }
+        Some(request.to_string())
+    }
+
+    /// Drops responses of objects changed by the update.
+    pub(crate) fn invalidate(&self, update: &Value) {
+        let tags = update_tags(update);
+        if tags.is_empty() {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        state.entries.retain(|_, entry| !tags.contains(&entry.tag));
+        for in_flight in state.in_flight.values_mut() {
+            if tags.contains(&in_flight.tag) {
+                in_flight.stale = true;
+            }
+        }
+    }
+
+    /// Returns cached response, waits for identical request sent by someone else or sends the request.
+    pub(crate) async fn get_or_fetch<F>(
+        &self,
+        key: String,
+        request: &Value,
+        fetch: F,
+    ) -> Result<Value>
+    where
+        F: Future<Output = Result<Value>>,
+    {
+        let tag = match request_tag(request) {
+            None => return fetch.await,
+            Some(tag) => tag,
+        };
+        let lookup = {
+            let mut state = self.state.lock().unwrap();
+            let now = Instant::now();
+            match state.entries.get(&key) {
+                Some(entry) if entry.expires_at > now => Lookup::Hit(entry.value.clone()),
+                _ => match state.in_flight.get_mut(&key) {
+                    Some(in_flight) => {
+                        let (sender, receiver) = oneshot::channel();
+                        in_flight.followers.push(sender);
+                        Lookup::Follow(receiver)
+                    }
+                    None => {
+                        state.in_flight.insert(
+                            key.clone(),
+                            InFlight {
+                                tag,
+                                stale: false,
+                                followers: vec![],
+                            },
+                        );
+                        Lookup::Lead
+                    }
+                },
+            }
+        };
+
+        match lookup {
+            Lookup::Hit(value) => Ok(value),
+            Lookup::Follow(receiver) => match receiver.await {
+                Ok(outcome) => outcome,
+                Err(_) => fetch.await,
+            },
+            Lookup::Lead => {
+                let mut guard = LeaderGuard {
+                    cache: self,
+                    key: Some(key),
+                };
+                let result = fetch.await;
+                if let Some(key) = guard.key.take() {
+                    self.complete(key, tag, &result);
+                }
+                result
+            }
+        }
+    }
+
+    fn complete(&self, key: String, tag: Tag, result: &Result<Value>) {
+        let mut state = self.state.lock().unwrap();
+        let in_flight = match state.in_flight.remove(&key) {
+            None => return,
+            Some(in_flight) => in_flight,
+        };
+        for follower in in_flight.followers {
+            let outcome = match result {
+                Ok(value) => Ok(value.clone()),
+                Err(err) => Err(copy_error(err)),
+            };
+            let _ = follower.send(outcome);
+        }
+        let value = match result {
+            Ok(value) if !in_flight.stale => value,
+            _ => return,
+        };
+
+        let now = Instant::now();
+        if state.entries.len() >= self.settings.max_entries {
+            state.entries.retain(|_, entry| entry.expires_at > now);
+        }
+        if state.entries.len() >= self.settings.max_entries {
+            let oldest = state
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(key, _)| key.clone());
+            if let Some(oldest) = oldest {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.entries.insert(
+            key,
+            Entry {
+                value: value.clone(),
+                tag,
+                expires_at: now + self.settings.ttl,
+            },
+        );
+    }
+}
+
+#[derive(Debug)]
+pub struct RequestCacheBuilder {
+    settings: RequestCacheSettings,
+}
+
+impl Default for RequestCacheBuilder {
+    fn default() -> Self {
+        Self {
+            settings: RequestCacheSettings {
+                ttl: Duration::from_secs(5),
+                max_entries: 1000,
+            },
+        }
+    }
+}
+
+impl RequestCacheBuilder {
+    /// Specifies how long, in seconds, responses are kept; at most a year. Default is 5.
+    /// Negative TTL is treated as 0, which disables caching; NaN is ignored.
+    pub fn with_ttl(mut self, ttl: f64) -> Self {
+        if let Some(ttl) = seconds::duration(ttl, 0.) {
+            self.settings.ttl = ttl.min(MAX_TTL);
+        }
+        self
+    }
+
+    /// Maximum number of cached responses. Default is 1000.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.settings.max_entries = max_entries.max(1);
+        self
+    }
+
+    pub fn build(self) -> RequestCache {
+        RequestCache {
+            settings: Arc::new(self.settings),
+            state: Arc::new(Mutex::new(CacheState::default())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RequestCache, MAX_TTL};
+    use futures::executor::block_on;
+    use serde_json::{json, Value};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[test]
+    fn cache_and_invalidate() {
+        let cache = RequestCache::builder().with_ttl(60.).build();
+        let calls = AtomicUsize::new(0);
+        let fetch = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(json!({"@type": "user", "id": 1}))
+        };
+        let request = json!({"@type": "getUser", "user_id": 1, "@extra": "a"});
+        let other_extra = json!({"@type": "getUser", "user_id": 1, "@extra": "b"});
+        let key = cache.key(&request).unwrap();
+        assert_eq!(cache.key(&other_extra), Some(key.clone()));
+        assert_eq!(
+            cache.key(&json!({"@type": "sendMessage", "chat_id": 1})),
+            None
+        );
+
+        block_on(cache.get_or_fetch(key.clone(), &request, fetch())).unwrap();
+        block_on(cache.get_or_fetch(key.clone(), &request, fetch())).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        cache.invalidate(&json!({"@type": "updateChatTitle", "chat_id": 1, "title": ""}));
+        assert_eq!(cache.len(), 1);
+        cache.invalidate(&json!({"@type": "updateUser", "user": {"id": 1}}));
+        assert!(cache.is_empty());
+
+        let value: Value = block_on(cache.get_or_fetch(key, &request, fetch())).unwrap();
+        assert_eq!(value["id"], 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn merge_concurrent() {
+        let cache = RequestCache::builder().build();
+        let request = json!({"@type": "getChat", "chat_id": 7});
+        let key = cache.key(&request).unwrap();
+        let (sender, receiver) = futures::channel::oneshot::channel::<()>();
+        let leader = cache.get_or_fetch(key.clone(), &request, async {
+            receiver.await.unwrap();
+            Ok(json!({"@type": "chat", "id": 7}))
+        });
+        let follower = cache.get_or_fetch(key, &request, async {
+            panic!("identical request must not be sent twice")
+        });
+        let (leader, follower) = block_on(async {
+            let sender = async move { sender.send(()).unwrap() };
+            let (leader, follower, _) = futures::join!(leader, follower, sender);
+            (leader, follower)
+        });
+        assert_eq!(leader.unwrap(), follower.unwrap());
+    }
+
+    #[test]
+    fn ttl_validated() {
+        let cache = RequestCache::builder().with_ttl(-1.).build();
+        assert_eq!(cache.settings.ttl, Duration::ZERO);
+        let cache = RequestCache::builder().with_ttl(f64::NAN).build();
+        assert_eq!(cache.settings.ttl, Duration::from_secs(5));
+
+        let cache = RequestCache::builder().with_ttl(f64::INFINITY).build();
+        assert_eq!(cache.settings.ttl, MAX_TTL);
+        let request = json!({"@type": "getChat", "chat_id": 1});
+        let key = cache.key(&request).unwrap();
+        let value = block_on(cache.get_or_fetch(key, &request, async { Ok(json!({})) }));
+        assert!(value.is_ok());
+    }
+}
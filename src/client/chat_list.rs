@@ -0,0 +1,363 @@
+use super::json::id;
+use super::snapshot::Snapshot;
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{
+    ChatList, ChatListArchive, ChatListFilter, ChatListFolder, ChatListMain, LoadChats,
+};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, RwLock};
+
+const LOAD_CHATS_LIMIT: usize = 100;
+const CHAT_LIST_NOT_FOUND_CODE: i32 = 404;
+const CHAT_LISTS_NOT_SPECIFIED: Error = Error::BadRequest(
+    "chat lists not specified, need to call `ClientBuilder::with_chat_lists(...)`",
+);
+
+/// Identifies [ChatList](crate::types::ChatList) and can be used as a map key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChatListKind {
+    Main,
+    Archive,
+    Filter(i32),
+    Folder(i32),
+}
+
+impl ChatListKind {
+    pub fn chat_list(&self) -> ChatList {
+        match self {
+            ChatListKind::Main => ChatList::Main(ChatListMain::builder().build()),
+            ChatListKind::Archive => ChatList::Archive(ChatListArchive::builder().build()),
+            ChatListKind::Filter(id) => {
+                ChatList::Filter(ChatListFilter::builder().chat_filter_id(*id).build())
+            }
+            ChatListKind::Folder(id) => {
+                ChatList::Folder(ChatListFolder::builder().chat_folder_id(*id).build())
+            }
+        }
+    }
+
+    /// Parses raw TDLib `chatList*` object.
+    pub(crate) fn from_value(list: &Value) -> Option<Self> {
+        let id = |key| {
+            list.get(key)
+                .and_then(|v| v.as_i64())
+                .and_then(|v| i32::try_from(v).ok())
+                .unwrap_or_default()
+        };
+        match list.get("@type")?.as_str()? {
+            "chatListMain" => Some(ChatListKind::Main),
+            "chatListArchive" => Some(ChatListKind::Archive),
+            "chatListFilter" => Some(ChatListKind::Filter(id("chat_filter_id"))),
+            "chatListFolder" => Some(ChatListKind::Folder(id("chat_folder_id"))),
+            _ => None,
+        }
+    }
+}
+
+impl From<&ChatList> for ChatListKind {
+    fn from(list: &ChatList) -> Self {
+        match list {
+            ChatList::Archive(_) => ChatListKind::Archive,
+            ChatList::Filter(filter) => ChatListKind::Filter(filter.chat_filter_id()),
+            ChatList::Folder(folder) => ChatListKind::Folder(folder.chat_folder_id()),
+            ChatList::Main(_) | ChatList::_Default => ChatListKind::Main,
+        }
+    }
+}
+
+/// Position of a chat in the [ChatLists](crate::client::chat_list::ChatLists).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatListEntry {
+    chat_id: i64,
+    order: i64,
+    is_pinned: bool,
+}
+
+impl ChatListEntry {
+    pub fn chat_id(&self) -> i64 {
+        self.chat_id
+    }
+
+    pub fn order(&self) -> i64 {
+        self.order
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.is_pinned
+    }
+}
+
+#[derive(Debug, Default)]
+struct OrderedList {
+    // (order, chat_id), iterated in reverse: TDLib sorts chats by descending order, then by descending chat identifier
+    sorted: BTreeSet<(i64, i64)>,
+    entries: HashMap<i64, ChatListEntry>,
+    is_complete: bool,
+}
+
+impl OrderedList {
+    fn remove(&mut self, chat_id: i64) {
+        if let Some(entry) = self.entries.remove(&chat_id) {
+            self.sorted.remove(&(entry.order, chat_id));
+        }
+    }
+
+    fn set(&mut self, entry: ChatListEntry) {
+        self.remove(entry.chat_id);
+        if entry.order != 0 {
+            self.sorted.insert((entry.order, entry.chat_id));
+            self.entries.insert(entry.chat_id, entry);
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &ChatListEntry> {
+        self.sorted
+            .iter()
+            .rev()
+            .filter_map(move |(_, chat_id)| self.entries.get(chat_id))
+    }
+}
+
+/// Sorted chat lists, built from [UpdateNewChat](crate::types::UpdateNewChat), [UpdateChatPosition](crate::types::UpdateChatPosition),
+/// [UpdateChatLastMessage](crate::types::UpdateChatLastMessage) and [UpdateChatDraftMessage](crate::types::UpdateChatDraftMessage) of particular client.
+/// Chats are ordered the same way as in official apps; pinned chats always have the greatest order, so they come first.
+/// Use [ClientBuilder::with_chat_lists](crate::client::ClientBuilder::with_chat_lists) to enable it
+/// and [Client::load_top_chats](crate::client::Client::load_top_chats) to load chats which are not received yet.
+#[derive(Debug, Clone, Default)]
+pub struct ChatLists {
+    lists: Arc<RwLock<HashMap<ChatListKind, OrderedList>>>,
+}
+
+fn entry(chat_id: i64, position: &Value) -> Option<(ChatListKind, ChatListEntry)> {
+    let list = ChatListKind::from_value(position.get("list")?)?;
+    let entry = ChatListEntry {
+        chat_id,
+        order: id(position, "order").unwrap_or_default(),
+        is_pinned: position
+            .get("is_pinned")
+            .and_then(|v| v.as_bool())
+            .unwrap_or_default(),
+    };
+    Some((list, entry))
+}
+
+impl ChatLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns first `n` chats of the list.
+    pub fn top(&self, list: ChatListKind, n: usize) -> Vec<ChatListEntry> {
+        self.lists
+            .read()
+            .unwrap()
+            .get(&list)
+            .map(|list| list.iter().take(n).copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns identifiers of all known chats of the list in their order.
+    pub fn chat_ids(&self, list: ChatListKind) -> Vec<i64> {
+        self.lists
+            .read()
+            .unwrap()
+            .get(&list)
+            .map(|list| list.iter().map(|entry| entry.chat_id).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns pinned chats of the list in their order.
+    pub fn pinned(&self, list: ChatListKind) -> Vec<ChatListEntry> {
+        self.lists
+            .read()
+            .unwrap()
+            .get(&list)
+            .map(|list| {
+                list.iter()
+                    .take_while(|entry| entry.is_pinned)
+                    .copied()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns position of the chat in the list, if the chat belongs to it.
+    pub fn position(&self, list: ChatListKind, chat_id: i64) -> Option<ChatListEntry> {
+        self.lists
+            .read()
+            .unwrap()
+            .get(&list)?
+            .entries
+            .get(&chat_id)
+            .copied()
+    }
+
+    /// Returns number of known chats in the list.
+    pub fn len(&self, list: ChatListKind) -> usize {
+        self.lists
+            .read()
+            .unwrap()
+            .get(&list)
+            .map(|list| list.entries.len())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if TDLib reported that all chats of the list are loaded.
+    pub fn is_complete(&self, list: ChatListKind) -> bool {
+        self.lists
+            .read()
+            .unwrap()
+            .get(&list)
+            .map(|list| list.is_complete)
+            .unwrap_or_default()
+    }
+
+    fn set_complete(&self, list: ChatListKind) {
+        self.lists
+            .write()
+            .unwrap()
+            .entry(list)
+            .or_default()
+            .is_complete = true;
+    }
+
+    /// Copies current lists into the snapshot.
+    pub(crate) fn export(&self, snapshot: &mut Snapshot) {
+        snapshot.chat_lists = self
+            .lists
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(kind, list)| (*kind, list.iter().copied().collect()))
+            .collect();
+    }
+
+    /// Fills lists with positions from the snapshot. Positions already received from TDLib are kept.
+    /// Restored lists are not complete, because chats could be added or removed while the application was stopped.
+    pub(crate) fn restore(&self, snapshot: &Snapshot) {
+        let mut lists = self.lists.write().unwrap();
+        for (kind, entries) in &snapshot.chat_lists {
+            let list = lists.entry(*kind).or_default();
+            for entry in entries {
+                if !list.entries.contains_key(&entry.chat_id) {
+                    list.set(*entry);
+                }
+            }
+        }
+    }
+
+    /// Applies raw TDLib update.
+    pub(crate) fn apply(&self, update: &Value) {
+        let (chat_id, positions, replace) = match update.get("@type").and_then(|t| t.as_str()) {
+            Some("updateNewChat") => {
+                let chat = match update.get("chat") {
+                    None => return,
+                    Some(chat) => chat,
+                };
+                (id(chat, "id"), chat.get("positions"), true)
+            }
+            Some("updateChatLastMessage") | Some("updateChatDraftMessage") => {
+                (id(update, "chat_id"), update.get("positions"), true)
+            }
+            Some("updateChatPosition") => (id(update, "chat_id"), update.get("position"), false),
+            _ => return,
+        };
+        let chat_id = match chat_id {
+            None => return,
+            Some(chat_id) => chat_id,
+        };
+        let positions: Vec<&Value> = match positions {
+            Some(Value::Array(positions)) => positions.iter().collect(),
+            Some(position) if !replace => vec![position],
+            _ => vec![],
+        };
+
+        let mut lists = self.lists.write().unwrap();
+        // list of positions is full, so the chat is removed from lists which aren't mentioned
+        if replace {
+            for list in lists.values_mut() {
+                list.remove(chat_id);
+            }
+        }
+        for (list, entry) in positions.into_iter().filter_map(|p| entry(chat_id, p)) {
+            lists.entry(list).or_default().set(entry);
+        }
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Loads chats of the list with [LoadChats](crate::types::LoadChats) until at least `n` chats are known
+    /// or TDLib reports that the whole list is loaded, then returns first `n` chats of the list.
+    /// Requires [ClientBuilder::with_chat_lists](crate::client::ClientBuilder::with_chat_lists).
+    pub async fn load_top_chats(&self, list: ChatListKind, n: usize) -> Result<Vec<ChatListEntry>> {
+        let chat_lists = self.chat_lists().ok_or(CHAT_LISTS_NOT_SPECIFIED)?;
+        while !chat_lists.is_complete(list) && chat_lists.len(list) < n {
+            let limit = (n - chat_lists.len(list)).min(LOAD_CHATS_LIMIT);
+            let request = LoadChats::builder()
+                .chat_list(list.chat_list())
+                .limit(limit as i32)
+                .build();
+            match self.load_chats(request).await {
+                Ok(_) => {}
+                Err(Error::TDLibError(err)) if err.code() == CHAT_LIST_NOT_FOUND_CODE => {
+                    chat_lists.set_complete(list)
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(chat_lists.top(list, n))
+    }
+
+    /// Loads all chats of the list, see [Client::load_top_chats](crate::client::Client::load_top_chats).
+    pub async fn load_all_chats(&self, list: ChatListKind) -> Result<()> {
+        self.load_top_chats(list, usize::MAX).await.map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChatListKind, ChatLists};
+    use serde_json::json;
+
+    fn position(list: serde_json::Value, order: i64, is_pinned: bool) -> serde_json::Value {
+        json!({"@type": "chatPosition", "list": list, "order": order.to_string(), "is_pinned": is_pinned})
+    }
+
+    #[test]
+    fn keep_order() {
+        let lists = ChatLists::new();
+        let main = json!({"@type": "chatListMain"});
+        let filter = json!({"@type": "chatListFilter", "chat_filter_id": 3});
+        lists.apply(&json!({"@type": "updateNewChat", "chat": {"id": 1, "positions": [position(main.clone(), 10, false)]}}));
+        lists.apply(&json!({"@type": "updateChatPosition", "chat_id": 2, "position": position(main.clone(), 20, false)}));
+        lists.apply(&json!({"@type": "updateChatPosition", "chat_id": 3, "position": position(main.clone(), 10, false)}));
+        lists.apply(&json!({"@type": "updateChatPosition", "chat_id": 4, "position": position(main.clone(), 100, true)}));
+        lists.apply(&json!({"@type": "updateChatPosition", "chat_id": 4, "position": position(filter.clone(), 5, false)}));
+        assert_eq!(lists.chat_ids(ChatListKind::Main), vec![4, 2, 3, 1]);
+        assert_eq!(lists.pinned(ChatListKind::Main).len(), 1);
+        assert_eq!(lists.chat_ids(ChatListKind::Filter(3)), vec![4]);
+
+        lists.apply(&json!({"@type": "updateChatLastMessage", "chat_id": 1, "positions": [position(main.clone(), 30, false)]}));
+        assert_eq!(
+            lists
+                .top(ChatListKind::Main, 2)
+                .iter()
+                .map(|e| e.chat_id())
+                .collect::<Vec<_>>(),
+            vec![4, 1]
+        );
+
+        lists.apply(&json!({"@type": "updateChatPosition", "chat_id": 2, "position": position(main.clone(), 0, false)}));
+        lists.apply(&json!({"@type": "updateChatDraftMessage", "chat_id": 4, "positions": [position(main, 100, true)]}));
+        assert_eq!(lists.chat_ids(ChatListKind::Main), vec![4, 1, 3]);
+        assert!(lists.position(ChatListKind::Filter(3), 4).is_none());
+        assert_eq!(lists.len(ChatListKind::Main), 3);
+        assert!(!lists.is_complete(ChatListKind::Main));
+    }
+}
@@ -0,0 +1,522 @@
+use super::auth_handler::{
+    AuthStateHandler, ConsoleAuthStateHandler, SignalAuthStateHandler, WaitPasswordAction,
+};
+use super::logging::TdlibLogging;
+use super::proxy::ProxyManager;
+use super::tdlib_client::TdJson;
+use super::worker::{Worker, WorkerBuilder};
+use super::{Client, ClientBuilder};
+use crate::errors::{Error, Result};
+use crate::types::{
+    AuthorizationState, AuthorizationStateWaitCode, AuthorizationStateWaitEncryptionKey,
+    AuthorizationStateWaitOtherDeviceConfirmation, AuthorizationStateWaitPassword,
+    AuthorizationStateWaitPhoneNumber, AuthorizationStateWaitRegistration, TdlibParameters,
+};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::sync::mpsc;
+
+const ENV_PREFIX: &str = "TDLIB_";
+
+/// Kind of [AuthStateHandler](crate::client::auth_handler::AuthStateHandler) the application wants to use.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthMode {
+    /// [ConsoleAuthStateHandler](crate::client::auth_handler::ConsoleAuthStateHandler)
+    #[default]
+    Console,
+    /// [SignalAuthStateHandler](crate::client::auth_handler::SignalAuthStateHandler)
+    Signal,
+}
+
+/// [AuthStateHandler](crate::client::auth_handler::AuthStateHandler) chosen by [auth_mode](crate::client::config::Config::auth_mode).
+#[derive(Debug, Clone)]
+pub enum ConfigAuthStateHandler {
+    Console(ConsoleAuthStateHandler),
+    Signal(SignalAuthStateHandler),
+}
+
+macro_rules! delegate {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        match $self {
+            ConfigAuthStateHandler::Console(handler) => handler.$method($($arg),*).await,
+            ConfigAuthStateHandler::Signal(handler) => handler.$method($($arg),*).await,
+        }
+    };
+}
+
+#[async_trait]
+impl AuthStateHandler for ConfigAuthStateHandler {
+    async fn handle_other_device_confirmation(
+        &self,
+        wait_device_confirmation: &AuthorizationStateWaitOtherDeviceConfirmation,
+    ) {
+        delegate!(self.handle_other_device_confirmation(wait_device_confirmation))
+    }
+
+    async fn handle_wait_code(&self, wait_code: &AuthorizationStateWaitCode) -> String {
+        delegate!(self.handle_wait_code(wait_code))
+    }
+
+    async fn handle_encryption_key(
+        &self,
+        wait_encryption_key: &AuthorizationStateWaitEncryptionKey,
+    ) -> String {
+        delegate!(self.handle_encryption_key(wait_encryption_key))
+    }
+
+    async fn handle_wait_password(&self, wait_password: &AuthorizationStateWaitPassword) -> String {
+        delegate!(self.handle_wait_password(wait_password))
+    }
+
+    async fn handle_wait_password_action(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> WaitPasswordAction {
+        delegate!(self.handle_wait_password_action(wait_password))
+    }
+
+    async fn handle_password_recovery_code(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> String {
+        delegate!(self.handle_password_recovery_code(wait_password))
+    }
+
+    async fn handle_new_password(
+        &self,
+        wait_password: &AuthorizationStateWaitPassword,
+    ) -> (String, String) {
+        delegate!(self.handle_new_password(wait_password))
+    }
+
+    async fn handle_wait_phone_number(
+        &self,
+        wait_phone_number: &AuthorizationStateWaitPhoneNumber,
+    ) -> String {
+        delegate!(self.handle_wait_phone_number(wait_phone_number))
+    }
+
+    async fn handle_wait_registration(
+        &self,
+        wait_registration: &AuthorizationStateWaitRegistration,
+    ) -> (String, String) {
+        delegate!(self.handle_wait_registration(wait_registration))
+    }
+
+    async fn handle_auth_error(
+        &self,
+        auth_state: &AuthorizationState,
+        error: &Error,
+        attempt: usize,
+    ) -> bool {
+        delegate!(self.handle_auth_error(auth_state, error, attempt))
+    }
+}
+
+/// Parameters of [TdlibParameters](crate::types::TdlibParameters). Default values are suitable for most desktop applications.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TdlibConfig {
+    pub use_test_dc: bool,
+    pub database_directory: String,
+    pub files_directory: String,
+    pub use_file_database: bool,
+    pub use_chat_info_database: bool,
+    pub use_message_database: bool,
+    pub use_secret_chats: bool,
+    pub api_id: i32,
+    pub api_hash: String,
+    pub system_language_code: String,
+    pub device_model: String,
+    pub system_version: String,
+    pub application_version: String,
+    pub enable_storage_optimizer: bool,
+    pub ignore_file_names: bool,
+}
+
+impl Default for TdlibConfig {
+    fn default() -> Self {
+        Self {
+            use_test_dc: false,
+            database_directory: "tddb".to_string(),
+            files_directory: String::new(),
+            use_file_database: true,
+            use_chat_info_database: true,
+            use_message_database: true,
+            use_secret_chats: false,
+            api_id: 0,
+            api_hash: String::new(),
+            system_language_code: "en".to_string(),
+            device_model: "Desktop".to_string(),
+            system_version: "Unknown".to_string(),
+            application_version: env!("CARGO_PKG_VERSION").to_string(),
+            enable_storage_optimizer: true,
+            ignore_file_names: false,
+        }
+    }
+}
+
+/// Settings of [WorkerBuilder](crate::client::worker::WorkerBuilder).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorkerConfig {
+    /// See [WorkerBuilder::with_read_updates_timeout](crate::client::worker::WorkerBuilder::with_read_updates_timeout)
+    pub read_updates_timeout: f64,
+    /// See [WorkerBuilder::with_channels_send_timeout](crate::client::worker::WorkerBuilder::with_channels_send_timeout)
+    pub channels_send_timeout: f64,
+    /// See [WorkerBuilder::with_auth_attempts](crate::client::worker::WorkerBuilder::with_auth_attempts)
+    pub auth_attempts: usize,
+}
+
+impl Default for WorkerConfig {
+    fn default() -> Self {
+        Self {
+            read_updates_timeout: 2.0,
+            channels_send_timeout: 5.0,
+            auth_attempts: 3,
+        }
+    }
+}
+
+/// Settings of [ProxyManager](crate::client::proxy::ProxyManager).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProxyConfig {
+    /// Proxy urls, see [parse_proxy_url](crate::client::proxy_url::parse_proxy_url)
+    pub urls: Vec<String>,
+    /// See [ProxyManagerBuilder::with_check_interval](crate::client::proxy::ProxyManagerBuilder::with_check_interval)
+    pub check_interval: Option<f64>,
+    /// See [ProxyManagerBuilder::with_max_failures](crate::client::proxy::ProxyManagerBuilder::with_max_failures)
+    pub max_failures: Option<usize>,
+    /// See [ProxyManagerBuilder::with_max_latency](crate::client::proxy::ProxyManagerBuilder::with_max_latency)
+    pub max_latency: Option<f64>,
+    /// See [ProxyManagerBuilder::with_connecting_timeout](crate::client::proxy::ProxyManagerBuilder::with_connecting_timeout)
+    pub connecting_timeout: Option<f64>,
+}
+
+/// Configuration of the client and the worker, loaded from JSON file.
+///
+/// Every parameter of [TdlibConfig](crate::client::config::TdlibConfig) may be overridden with `TDLIB_{PARAMETER}` environment variable,
+/// for example `TDLIB_API_ID` or `TDLIB_DATABASE_DIRECTORY`; `API_ID` and `API_HASH` are supported too.
+/// `TDLIB_LOG_VERBOSITY` overrides log verbosity and `TDLIB_PROXIES` overrides comma-separated proxy urls.
+/// ```no_run
+/// use rust_tdlib::client::config::Config;
+/// # async fn run() -> rust_tdlib::errors::Result<()> {
+/// let config = Config::load("tdlib.json")?;
+/// config.init_logging()?;
+/// let mut worker = config.worker_builder(None)?.build()?;
+/// worker.start();
+/// let client = worker.bind_client(config.client_builder()?.build()?, None).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub tdlib: TdlibConfig,
+    pub worker: WorkerConfig,
+    pub proxy: ProxyConfig,
+    /// TDLib log verbosity level: 0 - fatal errors, 1 - errors, 2 - warnings, 3 - info, 4 - debug, 5 - verbose debug
+    pub log_verbosity: Option<i32>,
+    /// Verbosity levels of particular TDLib log tags
+    pub log_tags: HashMap<String, i32>,
+    /// Forward TDLib log messages to the `log` crate, see [forward_to_log](crate::client::logging::forward_to_log)
+    pub forward_log: bool,
+    /// Auth state handler of [worker_builder](crate::client::config::Config::worker_builder)
+    pub auth_mode: AuthMode,
+}
+
+impl Config {
+    /// Reads config from JSON file, applies environment variables overrides and validates it.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut config = Self::from_file(path)?;
+        config.apply_env_overrides()?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Reads config from JSON file without validation.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_json(std::fs::read_to_string(path)?)
+    }
+
+    /// Parses config from JSON string without validation.
+    pub fn from_json<S: AsRef<str>>(json: S) -> Result<Self> {
+        Ok(serde_json::from_str(json.as_ref())?)
+    }
+
+    /// Applies environment variables overrides, see [Config](crate::client::config::Config).
+    pub fn apply_env_overrides(&mut self) -> Result<()> {
+        self.apply_overrides(|name| std::env::var(name).ok())
+    }
+
+    fn apply_overrides<F: Fn(&str) -> Option<String>>(&mut self, var: F) -> Result<()> {
+        let mut tdlib = serde_json::to_value(&self.tdlib)?;
+        if let Some(params) = tdlib.as_object_mut() {
+            for (key, value) in params.iter_mut() {
+                let name = format!("{}{}", ENV_PREFIX, key.to_uppercase());
+                let override_ = match (var(&name), key.as_str()) {
+                    (Some(v), _) => Some(v),
+                    (None, "api_id") => var("API_ID"),
+                    (None, "api_hash") => var("API_HASH"),
+                    _ => None,
+                };
+                if let Some(override_) = override_ {
+                    *value = match value {
+                        serde_json::Value::String(_) => serde_json::Value::String(override_),
+                        _ => serde_json::from_str(&override_)
+                            .map_err(|_| Error::BadRequest("invalid environment variable value"))?,
+                    };
+                }
+            }
+        }
+        self.tdlib = serde_json::from_value(tdlib)?;
+
+        if let Some(verbosity) = var("TDLIB_LOG_VERBOSITY") {
+            self.log_verbosity = Some(
+                verbosity
+                    .parse()
+                    .map_err(|_| Error::BadRequest("invalid TDLIB_LOG_VERBOSITY value"))?,
+            );
+        }
+        if let Some(proxies) = var("TDLIB_PROXIES") {
+            self.proxy.urls = proxies
+                .split(',')
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty())
+                .collect();
+        }
+        Ok(())
+    }
+
+    /// Checks parameters which TDLib requires to be set.
+    pub fn validate(&self) -> Result<()> {
+        if self.tdlib.api_id == 0 {
+            return Err(Error::BadRequest("api_id must be set"));
+        }
+        if self.tdlib.api_hash.is_empty() {
+            return Err(Error::BadRequest("api_hash must be non-empty"));
+        }
+        if self.tdlib.system_language_code.is_empty() {
+            return Err(Error::BadRequest("system_language_code must be non-empty"));
+        }
+        if self.tdlib.device_model.is_empty() {
+            return Err(Error::BadRequest("device_model must be non-empty"));
+        }
+        if self.tdlib.application_version.is_empty() {
+            return Err(Error::BadRequest("application_version must be non-empty"));
+        }
+        let durations = [
+            Some(self.worker.read_updates_timeout),
+            Some(self.worker.channels_send_timeout),
+            self.proxy.check_interval,
+            self.proxy.max_latency,
+            self.proxy.connecting_timeout,
+        ];
+        if durations
+            .into_iter()
+            .flatten()
+            .any(|duration| !duration.is_finite() || duration <= 0.)
+        {
+            return Err(Error::BadRequest(
+                "timeouts and intervals must be finite positive numbers",
+            ));
+        }
+        let verbosities = self.log_verbosity.iter().chain(self.log_tags.values());
+        for verbosity in verbosities {
+            if !(0..=1023).contains(verbosity) {
+                return Err(Error::BadRequest("log_verbosity must be in range 0..1023"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns [TdlibLogging](crate::client::logging::TdlibLogging) with specified verbosity levels.
+    pub fn logging(&self) -> TdlibLogging {
+        let mut builder = TdlibLogging::builder().with_tags_verbosity_levels(self.log_tags.clone());
+        if let Some(verbosity) = self.log_verbosity {
+            builder = builder.with_verbosity_level(verbosity);
+        }
+        if self.forward_log {
+            builder = builder.with_forwarding();
+        }
+        builder.build()
+    }
+
+    /// Applies TDLib log settings, see [logging](crate::client::config::Config::logging).
+    pub fn init_logging(&self) -> Result<()> {
+        self.logging().init()
+    }
+
+    pub fn tdlib_parameters(&self) -> TdlibParameters {
+        let tdlib = &self.tdlib;
+        TdlibParameters::builder()
+            .use_test_dc(tdlib.use_test_dc)
+            .database_directory(&tdlib.database_directory)
+            .files_directory(&tdlib.files_directory)
+            .use_file_database(tdlib.use_file_database)
+            .use_chat_info_database(tdlib.use_chat_info_database)
+            .use_message_database(tdlib.use_message_database)
+            .use_secret_chats(tdlib.use_secret_chats)
+            .api_id(tdlib.api_id)
+            .api_hash(&tdlib.api_hash)
+            .system_language_code(&tdlib.system_language_code)
+            .device_model(&tdlib.device_model)
+            .system_version(&tdlib.system_version)
+            .application_version(&tdlib.application_version)
+            .enable_storage_optimizer(tdlib.enable_storage_optimizer)
+            .ignore_file_names(tdlib.ignore_file_names)
+            .build()
+    }
+
+    /// Returns [ProxyManager](crate::client::proxy::ProxyManager) if any proxy url specified.
+    pub fn proxy_manager(&self) -> Result<Option<ProxyManager>> {
+        if self.proxy.urls.is_empty() {
+            return Ok(None);
+        }
+        let mut builder = ProxyManager::builder();
+        for url in self.proxy.urls.iter() {
+            builder = builder.with_proxy_url(url)?;
+        }
+        if let Some(check_interval) = self.proxy.check_interval {
+            builder = builder.with_check_interval(check_interval);
+        }
+        if let Some(max_failures) = self.proxy.max_failures {
+            builder = builder.with_max_failures(max_failures);
+        }
+        if let Some(max_latency) = self.proxy.max_latency {
+            builder = builder.with_max_latency(max_latency);
+        }
+        if let Some(connecting_timeout) = self.proxy.connecting_timeout {
+            builder = builder.with_connecting_timeout(connecting_timeout);
+        }
+        Ok(Some(builder.build()))
+    }
+
+    /// Returns [ClientBuilder](crate::client::ClientBuilder) with TDLib parameters and proxies.
+    pub fn client_builder(&self) -> Result<ClientBuilder<TdJson>> {
+        let builder = Client::builder().with_tdlib_parameters(self.tdlib_parameters());
+        Ok(match self.proxy_manager()? {
+            None => builder,
+            Some(proxy_manager) => builder.with_proxy_manager(proxy_manager),
+        })
+    }
+
+    /// Returns auth state handler chosen by [auth_mode](crate::client::config::Config::auth_mode).
+    /// `signals` receiver is passed to [SignalAuthStateHandler](crate::client::auth_handler::SignalAuthStateHandler) and required for [AuthMode::Signal](crate::client::config::AuthMode::Signal).
+    pub fn auth_state_handler(
+        &self,
+        signals: Option<mpsc::Receiver<String>>,
+    ) -> Result<ConfigAuthStateHandler> {
+        match (self.auth_mode, signals) {
+            (AuthMode::Console, _) => Ok(ConfigAuthStateHandler::Console(
+                ConsoleAuthStateHandler::new(),
+            )),
+            (AuthMode::Signal, Some(signals)) => Ok(ConfigAuthStateHandler::Signal(
+                SignalAuthStateHandler::new(signals),
+            )),
+            (AuthMode::Signal, None) => Err(Error::BadRequest(
+                "signals receiver is required for signal auth mode",
+            )),
+        }
+    }
+
+    /// Returns [WorkerBuilder](crate::client::worker::WorkerBuilder) with specified timeouts and auth state handler,
+    /// see [auth_state_handler](crate::client::config::Config::auth_state_handler).
+    pub fn worker_builder(
+        &self,
+        signals: Option<mpsc::Receiver<String>>,
+    ) -> Result<WorkerBuilder<ConfigAuthStateHandler, TdJson>> {
+        Ok(Worker::builder()
+            .with_read_updates_timeout(self.worker.read_updates_timeout)
+            .with_channels_send_timeout(self.worker.channels_send_timeout)
+            .with_auth_attempts(self.worker.auth_attempts)
+            .with_auth_state_handler(self.auth_state_handler(signals)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthMode, Config, ConfigAuthStateHandler};
+    use std::collections::HashMap;
+
+    #[test]
+    fn load_with_overrides() {
+        let mut config = Config::from_json(
+            r#"{"tdlib": {"api_hash": "hash", "database_directory": "db"}, "auth_mode": "signal"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.auth_mode, AuthMode::Signal);
+        assert_eq!(config.tdlib.device_model, "Desktop");
+        assert!(config.validate().is_err());
+
+        let env: HashMap<&str, &str> = [
+            ("API_ID", "42"),
+            ("TDLIB_DATABASE_DIRECTORY", "other_db"),
+            ("TDLIB_USE_TEST_DC", "true"),
+            ("TDLIB_LOG_VERBOSITY", "2"),
+            (
+                "TDLIB_PROXIES",
+                "socks5://127.0.0.1:1080, http://127.0.0.1:3128",
+            ),
+        ]
+        .into_iter()
+        .collect();
+        config
+            .apply_overrides(|name| env.get(name).map(|v| v.to_string()))
+            .unwrap();
+        config.validate().unwrap();
+
+        let params = config.tdlib_parameters();
+        assert_eq!(params.api_id(), 42);
+        assert_eq!(params.api_hash(), "hash");
+        assert_eq!(params.database_directory(), "other_db");
+        assert!(params.use_test_dc());
+        assert_eq!(config.log_verbosity, Some(2));
+        assert_eq!(config.proxy.urls.len(), 2);
+        assert!(config.proxy_manager().unwrap().is_some());
+    }
+
+    #[test]
+    fn validate_required() {
+        let mut config = Config::default();
+        config.tdlib.api_id = 1;
+        config.tdlib.api_hash = "hash".to_string();
+        config.validate().unwrap();
+
+        config.log_tags.insert("td_requests".to_string(), 2048);
+        assert!(config.validate().is_err());
+        config.log_tags.clear();
+
+        config.worker.read_updates_timeout = -1.;
+        assert!(config.validate().is_err());
+        config.worker.read_updates_timeout = 2.;
+        config.proxy.check_interval = Some(f64::NAN);
+        assert!(config.validate().is_err());
+        config.proxy.check_interval = Some(f64::INFINITY);
+        assert!(config.validate().is_err());
+        config.proxy.check_interval = Some(60.);
+        config.validate().unwrap();
+
+        config.tdlib.system_language_code = String::new();
+        assert!(config.validate().is_err());
+    }
+
+    #[test]
+    fn auth_mode_handler() {
+        let mut config = Config::default();
+        assert!(matches!(
+            config.auth_state_handler(None).unwrap(),
+            ConfigAuthStateHandler::Console(_)
+        ));
+        config.auth_mode = AuthMode::Signal;
+        assert!(config.auth_state_handler(None).is_err());
+        let (_, signals) = tokio::sync::mpsc::channel(1);
+        assert!(matches!(
+            config.auth_state_handler(Some(signals)).unwrap(),
+            ConfigAuthStateHandler::Signal(_)
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::types::ConnectionState;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Short form of [ConnectionState](crate::types::ConnectionState).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConnectionStateKind {
+    /// Currently waiting for the network to become available
+    WaitingForNetwork,
+    /// Currently establishing a connection with a proxy server
+    ConnectingToProxy,
+    /// Currently establishing a connection to the Telegram servers
+    Connecting,
+    /// Downloading data received while the application was offline
+    Updating,
+    /// There is a working connection to the Telegram servers
+    Ready,
+}
+
+impl ConnectionStateKind {
+    /// Returns `None` for default (unknown) state.
+    pub fn from_connection_state(connection_state: &ConnectionState) -> Option<Self> {
+        match connection_state {
+            ConnectionState::_Default => None,
+            ConnectionState::WaitingForNetwork(_) => Some(Self::WaitingForNetwork),
+            ConnectionState::ConnectingToProxy(_) => Some(Self::ConnectingToProxy),
+            ConnectionState::Connecting(_) => Some(Self::Connecting),
+            ConnectionState::Updating(_) => Some(Self::Updating),
+            ConnectionState::Ready(_) => Some(Self::Ready),
+        }
+    }
+}
+
+/// Describes connection state change, see [Client::connection_transitions](crate::client::Client::connection_transitions).
+#[derive(Debug, Clone)]
+pub struct ConnectionTransition {
+    from: Option<ConnectionStateKind>,
+    to: ConnectionStateKind,
+    elapsed: Duration,
+}
+
+impl ConnectionTransition {
+    /// Previous state; `None` if it's the first state received from TDLib.
+    pub fn from(&self) -> Option<ConnectionStateKind> {
+        self.from
+    }
+
+    /// New state.
+    pub fn to(&self) -> ConnectionStateKind {
+        self.to
+    }
+
+    /// Time spent in the previous state.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Time spent by the client in each connection state, see [Client::connection_stats](crate::client::Client::connection_stats).
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionStats {
+    current: Option<(ConnectionStateKind, Instant)>,
+    durations: HashMap<ConnectionStateKind, Duration>,
+    transitions: usize,
+}
+
+impl ConnectionStats {
+    /// Current state.
+    pub fn current(&self) -> Option<ConnectionStateKind> {
+        self.current.map(|(kind, _)| kind)
+    }
+
+    /// Time spent in the current state.
+    pub fn current_duration(&self) -> Duration {
+        self.current
+            .map(|(_, since)| since.elapsed())
+            .unwrap_or_default()
+    }
+
+    /// Total time spent in the state, including the current one.
+    pub fn total_duration(&self, kind: ConnectionStateKind) -> Duration {
+        let mut total = self.durations.get(&kind).copied().unwrap_or_default();
+        if self.current() == Some(kind) {
+            total += self.current_duration();
+        }
+        total
+    }
+
+    /// Number of state changes received from TDLib.
+    pub fn transitions(&self) -> usize {
+        self.transitions
+    }
+
+    // Returns `None` if the state was not changed.
+    pub(crate) fn apply(&mut self, kind: ConnectionStateKind) -> Option<ConnectionTransition> {
+        let now = Instant::now();
+        let (from, elapsed) = match self.current {
+            Some((current, _)) if current == kind => return None,
+            Some((current, since)) => {
+                let elapsed = now.duration_since(since);
+                *self.durations.entry(current).or_default() += elapsed;
+                (Some(current), elapsed)
+            }
+            None => (None, Duration::default()),
+        };
+        self.current = Some((kind, now));
+        self.transitions += 1;
+        Some(ConnectionTransition {
+            from,
+            to: kind,
+            elapsed,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConnectionStateKind, ConnectionStats};
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn apply_states() {
+        let mut stats = ConnectionStats::default();
+        let transition = stats.apply(ConnectionStateKind::Connecting).unwrap();
+        assert_eq!(transition.from(), None);
+        assert_eq!(transition.elapsed(), Duration::ZERO);
+        sleep(Duration::from_millis(10));
+        assert!(stats.apply(ConnectionStateKind::Connecting).is_none());
+        assert_eq!(stats.transitions(), 1);
+
+        let transition = stats.apply(ConnectionStateKind::Ready).unwrap();
+        assert_eq!(transition.from(), Some(ConnectionStateKind::Connecting));
+        assert_eq!(transition.to(), ConnectionStateKind::Ready);
+        // repeated state didn't reset the time spent in it
+        assert!(transition.elapsed() >= Duration::from_millis(10));
+        stats.apply(ConnectionStateKind::Connecting).unwrap();
+        stats.apply(ConnectionStateKind::Ready).unwrap();
+
+        assert_eq!(stats.transitions(), 4);
+        assert_eq!(stats.current(), Some(ConnectionStateKind::Ready));
+        assert!(stats.total_duration(ConnectionStateKind::Connecting) >= transition.elapsed());
+        assert!(stats.total_duration(ConnectionStateKind::Ready) >= stats.current_duration());
+        assert_eq!(
+            stats.total_duration(ConnectionStateKind::WaitingForNetwork),
+            Duration::ZERO
+        );
+    }
+}
@@ -0,0 +1,613 @@
+//! Managed file downloads.
+//!
+//! [DownloadFile](crate::types::DownloadFile) returns immediately, and download progress is reported only with [UpdateFile](crate::types::UpdateFile).
+//! [DownloadManager](crate::client::download::DownloadManager) tracks these updates for every started download,
+//! limits number of simultaneously active downloads and starts queued ones by priority.
+//! Files which weren't downloaded before exit can be started again with [Client::resume_downloads](crate::client::Client::resume_downloads);
+//! TDLib continues them from the downloaded part.
+//! ```no_run
+//! # use rust_tdlib::client::{download::DownloadManager, Client, tdlib_client::TdJson};
+//! # async fn run(client: Client<TdJson>, file_id: i32) -> rust_tdlib::errors::Result<()> {
+//! // the client is built with `.with_download_manager(DownloadManager::builder().with_max_active(3).build())`
+//! let download = client.download(file_id, 16)?;
+//! let mut progress = download.progress_receiver();
+//! tokio::spawn(async move {
+//!     while progress.changed().await.is_ok() {
+//!         let progress = progress.borrow().clone();
+//!         println!("{}/{}", progress.downloaded_size(), progress.size());
+//!     }
+//! });
+//! let path = download.wait().await?;
+//! # Ok(())
+//! # }
+//! ```
+use super::tdlib_client::TdLibClient;
+use super::transfer::{file_size, TransferHandle, TransferProgress};
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{CancelDownloadFile, DownloadFile, Error as TDLibError, File};
+use serde_json::Value;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{oneshot, watch};
+
+const MIN_PRIORITY: i32 = 1;
+const MAX_PRIORITY: i32 = 32;
+const DOWNLOAD_MANAGER_NOT_SPECIFIED: Error = Error::BadRequest(
+    "download manager not specified, need to call `ClientBuilder::with_download_manager(...)`",
+);
+const WORKER_NOT_BOUND: Error =
+    Error::BadRequest("managed downloads require the client bound with worker");
+const DOWNLOAD_CANCELLED: Error = Error::Internal("download cancelled");
+
+/// State of a managed download.
+#[derive(Debug, Clone)]
+pub enum DownloadStatus {
+    /// Waiting for a free slot.
+    Queued,
+    Active,
+    Completed,
+    /// Cancelled with [Client::cancel_download](crate::client::Client::cancel_download) or stopped by TDLib.
+    Cancelled,
+    Failed(TDLibError),
+}
+
+/// Progress of a managed download.
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    file_id: i32,
+    priority: i32,
+    status: DownloadStatus,
+    downloaded_size: i64,
+    size: i64,
+    path: String,
+}
+
+impl DownloadProgress {
+    fn new(file_id: i32, priority: i32) -> Self {
+        Self {
+            file_id,
+            priority,
+            status: DownloadStatus::Queued,
+            downloaded_size: 0,
+            size: 0,
+            path: String::new(),
+        }
+    }
+
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn status(&self) -> &DownloadStatus {
+        &self.status
+    }
+
+    pub fn downloaded_size(&self) -> i64 {
+        self.downloaded_size
+    }
+
+    /// File size or its expected size; 0 if unknown.
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    /// Local path of the file; available when some part of the file is downloaded.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl TransferProgress for DownloadProgress {
+    /// Local path of the downloaded file.
+    type Output = String;
+
+    const UNTRACKED: Error = DOWNLOAD_CANCELLED;
+
+    /// `true` if the download is completed, cancelled or failed.
+    fn is_finished(&self) -> bool {
+        !matches!(self.status, DownloadStatus::Queued | DownloadStatus::Active)
+    }
+
+    fn result(&self) -> Option<Result<String>> {
+        match &self.status {
+            DownloadStatus::Queued | DownloadStatus::Active => None,
+            DownloadStatus::Completed => Some(Ok(self.path.clone())),
+            DownloadStatus::Cancelled => Some(Err(DOWNLOAD_CANCELLED)),
+            DownloadStatus::Failed(err) => Some(Err(Error::TDLibError(err.clone()))),
+        }
+    }
+}
+
+/// Unfinished download, which can be saved before exit and passed to [Client::resume_downloads](crate::client::Client::resume_downloads) after restart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingDownload {
+    file_id: i32,
+    priority: i32,
+}
+
+impl PendingDownload {
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+}
+
+/// Handle of a managed download, returned by [Client::download](crate::client::Client::download).
+/// [wait](crate::client::transfer::TransferHandle::wait) returns local path of the file.
+pub type Download = TransferHandle<DownloadProgress>;
+
+#[derive(Debug)]
+struct Tracked {
+    progress: watch::Sender<DownloadProgress>,
+    // sender which allows queued download to start
+    slot: Option<oneshot::Sender<()>>,
+    // order of enqueuing; queue entries of finished downloads have outdated order
+    seq: u64,
+    active: bool,
+    started: bool,
+}
+
+#[derive(Debug, Default)]
+struct DownloadState {
+    downloads: HashMap<i32, Tracked>,
+    // priority, order of enqueuing and file identifier of queued downloads
+    queue: BinaryHeap<(i32, Reverse<u64>, i32)>,
+    active: usize,
+    seq: u64,
+}
+
+impl DownloadState {
+    fn start(&mut self, file_id: i32, seq: u64) -> bool {
+        let tracked = match self.downloads.get_mut(&file_id) {
+            Some(tracked) if tracked.seq == seq => tracked,
+            _ => return false,
+        };
+        let sent = match tracked.slot.take() {
+            None => return false,
+            Some(slot) => slot.send(()).is_ok(),
+        };
+        if !sent {
+            // the task which starts the download is dropped, so the download will never start
+            if let Some(tracked) = self.downloads.remove(&file_id) {
+                tracked
+                    .progress
+                    .send_modify(|progress| progress.status = DownloadStatus::Cancelled);
+            }
+            return false;
+        }
+        tracked.active = true;
+        tracked
+            .progress
+            .send_modify(|progress| progress.status = DownloadStatus::Active);
+        self.active += 1;
+        true
+    }
+
+    fn start_queued(&mut self, max_active: usize) {
+        while self.active < max_active {
+            match self.queue.pop() {
+                None => return,
+                Some((_, Reverse(seq), file_id)) => {
+                    self.start(file_id, seq);
+                }
+            }
+        }
+    }
+
+    fn finish(&mut self, file_id: i32, status: DownloadStatus, max_active: usize) -> bool {
+        let tracked = match self.downloads.remove(&file_id) {
+            None => return false,
+            Some(tracked) => tracked,
+        };
+        let was_active = tracked.active;
+        tracked
+            .progress
+            .send_modify(|progress| progress.status = status);
+        if was_active {
+            self.active -= 1;
+            self.start_queued(max_active);
+        }
+        was_active
+    }
+}
+
+/// Tracks managed downloads of a client and limits number of simultaneously active ones.
+#[derive(Debug, Clone)]
+pub struct DownloadManager {
+    max_active: usize,
+    state: Arc<Mutex<DownloadState>>,
+}
+
+impl Default for DownloadManager {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl DownloadManager {
+    pub fn builder() -> DownloadManagerBuilder {
+        DownloadManagerBuilder::default()
+    }
+
+    pub fn max_active(&self) -> usize {
+        self.max_active
+    }
+
+    /// Returns progress of the queued or active download.
+    pub fn progress(&self, file_id: i32) -> Option<DownloadProgress> {
+        let state = self.state.lock().unwrap();
+        state
+            .downloads
+            .get(&file_id)
+            .map(|tracked| tracked.progress.borrow().clone())
+    }
+
+    /// Returns queued and active downloads.
+    pub fn pending(&self) -> Vec<PendingDownload> {
+        let state = self.state.lock().unwrap();
+        let mut pending: Vec<PendingDownload> = state
+            .downloads
+            .values()
+            .map(|tracked| {
+                let progress = tracked.progress.borrow();
+                PendingDownload {
+                    file_id: progress.file_id,
+                    priority: progress.priority,
+                }
+            })
+            .collect();
+        pending.sort_by_key(|download| (Reverse(download.priority), download.file_id));
+        pending
+    }
+
+    /// Starts tracking of the download. Returns receiver, which is notified when the download can be started, for a new download.
+    fn register(&self, file_id: i32, priority: i32) -> (Download, Option<oneshot::Receiver<()>>) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(tracked) = state.downloads.get(&file_id) {
+            return (Download::new(file_id, tracked.progress.subscribe()), None);
+        }
+        let (progress, receiver) = watch::channel(DownloadProgress::new(file_id, priority));
+        let (slot, slot_receiver) = oneshot::channel();
+        state.seq += 1;
+        let seq = state.seq;
+        state.downloads.insert(
+            file_id,
+            Tracked {
+                progress,
+                slot: Some(slot),
+                seq,
+                active: false,
+                started: false,
+            },
+        );
+        state.queue.push((priority, Reverse(seq), file_id));
+        state.start_queued(self.max_active);
+        (Download::new(file_id, receiver), Some(slot_receiver))
+    }
+
+    /// Updates progress with the file returned by TDLib. Returns `false` if the download isn't tracked anymore.
+    fn update(&self, file: &File, started: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let tracked = match state.downloads.get_mut(&file.id()) {
+            None => return false,
+            Some(tracked) => tracked,
+        };
+        tracked.started |= started;
+        tracked.progress.send_modify(|progress| {
+            progress.downloaded_size = file.local().downloaded_size();
+            progress.size = file_size(file);
+            progress.path = file.local().path().clone();
+        });
+        let local = file.local();
+        let status = if local.is_downloading_completed() {
+            DownloadStatus::Completed
+        } else if tracked.started && tracked.active && !local.is_downloading_active() {
+            // download was cancelled by someone else or can't be continued
+            DownloadStatus::Cancelled
+        } else {
+            return true;
+        };
+        state.finish(file.id(), status, self.max_active);
+        true
+    }
+
+    fn finish(&self, file_id: i32, status: DownloadStatus) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state.finish(file_id, status, self.max_active)
+    }
+
+    /// Applies raw TDLib update.
+    pub(crate) fn apply(&self, update: &Value) {
+        if update.get("@type").and_then(|t| t.as_str()) != Some("updateFile") {
+            return;
+        }
+        let file = match update
+            .get("file")
+            .cloned()
+            .map(serde_json::from_value::<File>)
+        {
+            Some(Ok(file)) => file,
+            _ => return,
+        };
+        self.update(&file, false);
+    }
+}
+
+#[derive(Debug)]
+pub struct DownloadManagerBuilder {
+    max_active: usize,
+}
+
+impl Default for DownloadManagerBuilder {
+    fn default() -> Self {
+        Self { max_active: 4 }
+    }
+}
+
+impl DownloadManagerBuilder {
+    /// Maximum number of simultaneously active downloads. Default is 4.
+    pub fn with_max_active(mut self, max_active: usize) -> Self {
+        self.max_active = max_active.max(1);
+        self
+    }
+
+    pub fn build(self) -> DownloadManager {
+        DownloadManager {
+            max_active: self.max_active,
+            state: Arc::new(Mutex::new(DownloadState::default())),
+        }
+    }
+}
+
+fn tdlib_error(err: Error) -> TDLibError {
+    match err {
+        Error::TDLibError(err) => err,
+        err => TDLibError::builder().message(err.to_string()).build(),
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    /// Starts managed download of the file with priority from 1 to 32; downloads with higher priority are started first.
+    /// If the file is already being downloaded, returns handle of the existing download.
+    pub fn download(&self, file_id: i32, priority: i32) -> Result<Download> {
+        let manager = self
+            .download_manager()
+            .ok_or(DOWNLOAD_MANAGER_NOT_SPECIFIED)?
+            .clone();
+        let executor = self.executor().ok_or(WORKER_NOT_BOUND)?;
+        let priority = priority.clamp(MIN_PRIORITY, MAX_PRIORITY);
+        let (download, slot) = manager.register(file_id, priority);
+        let slot = match slot {
+            None => return Ok(download),
+            Some(slot) => slot,
+        };
+        let client = self.clone();
+        executor.spawn(Box::pin(async move {
+            // error means that the download was cancelled while queued
+            if slot.await.is_err() {
+                return;
+            }
+            let request = DownloadFile::builder()
+                .file_id(file_id)
+                .priority(priority)
+                .build();
+            match client.download_file(request).await {
+                Ok(file) => {
+                    if !manager.update(&file, true) {
+                        // cancelled while the request was in progress
+                        let request = CancelDownloadFile::builder().file_id(file_id).build();
+                        if let Err(err) = client.cancel_download_file(request).await {
+                            log::error!("can't cancel download of file {}: {}", file_id, err);
+                        }
+                    }
+                }
+                Err(err) => {
+                    manager.finish(file_id, DownloadStatus::Failed(tdlib_error(err)));
+                }
+            }
+        }));
+        Ok(download)
+    }
+
+    /// Starts downloads saved with [DownloadManager::pending](crate::client::download::DownloadManager::pending) before restart.
+    pub fn resume_downloads(&self, pending: &[PendingDownload]) -> Result<Vec<Download>> {
+        pending
+            .iter()
+            .map(|download| self.download(download.file_id, download.priority))
+            .collect()
+    }
+
+    /// Cancels managed download with [CancelDownloadFile](crate::types::CancelDownloadFile).
+    pub async fn cancel_download(&self, file_id: i32) -> Result<()> {
+        let manager = self
+            .download_manager()
+            .ok_or(DOWNLOAD_MANAGER_NOT_SPECIFIED)?;
+        if manager.finish(file_id, DownloadStatus::Cancelled) {
+            let request = CancelDownloadFile::builder().file_id(file_id).build();
+            self.cancel_download_file(request).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DownloadManager, DownloadStatus};
+    use crate::client::mocked_raw_api::{error, ok, MockedRawApi};
+    use crate::client::{Client, Download};
+    use crate::errors::Error;
+    use serde_json::json;
+
+    fn update_file(file_id: i32, active: bool, completed: bool) -> serde_json::Value {
+        json!({
+            "@type": "updateFile",
+            "file": {
+                "@type": "file",
+                "id": file_id,
+                "size": 100,
+                "expected_size": 100,
+                "local": {
+                    "@type": "localFile",
+                    "path": if completed { "/tmp/file" } else { "" },
+                    "is_downloading_active": active,
+                    "is_downloading_completed": completed,
+                    "downloaded_size": if completed { 100 } else { 10 },
+                },
+                "remote": {"@type": "remoteFile", "id": "", "unique_id": ""},
+            }
+        })
+    }
+
+    #[test]
+    fn priority_queue() {
+        let manager = DownloadManager::builder().with_max_active(1).build();
+        let (first, first_slot) = manager.register(1, 1);
+        let (second, second_slot) = manager.register(2, 1);
+        let (third, third_slot) = manager.register(3, 5);
+        let (mut first_slot, mut second_slot, mut third_slot) = (
+            first_slot.unwrap(),
+            second_slot.unwrap(),
+            third_slot.unwrap(),
+        );
+        assert!(first_slot.try_recv().is_ok());
+        assert!(second_slot.try_recv().is_err());
+        assert!(third_slot.try_recv().is_err());
+        assert!(manager.register(1, 1).1.is_none());
+
+        manager.apply(&update_file(1, true, false));
+        assert_eq!(first.progress().downloaded_size(), 10);
+        manager.apply(&update_file(1, false, true));
+        assert!(matches!(
+            first.progress().status(),
+            DownloadStatus::Completed
+        ));
+        assert_eq!(first.progress().path(), "/tmp/file");
+        assert!(third_slot.try_recv().is_ok());
+        assert!(matches!(third.progress().status(), DownloadStatus::Active));
+        assert!(matches!(second.progress().status(), DownloadStatus::Queued));
+
+        assert!(!manager.finish(2, DownloadStatus::Cancelled));
+        assert!(second_slot.try_recv().is_err());
+        assert_eq!(manager.pending().len(), 1);
+    }
+
+    #[test]
+    fn dropped_slot() {
+        let manager = DownloadManager::builder().with_max_active(1).build();
+        let (first, _) = manager.register(1, 1);
+        // the task waiting for the slot is dropped before the download is started
+        let (second, second_slot) = manager.register(2, 1);
+        drop(second_slot);
+        let (_, third_slot) = manager.register(3, 1);
+        let mut third_slot = third_slot.unwrap();
+
+        manager.apply(&update_file(1, false, true));
+        assert!(matches!(
+            first.progress().status(),
+            DownloadStatus::Completed
+        ));
+        assert!(matches!(
+            second.progress().status(),
+            DownloadStatus::Cancelled
+        ));
+        assert!(third_slot.try_recv().is_ok());
+        assert_eq!(manager.pending().len(), 1);
+        assert!(manager.finish(3, DownloadStatus::Cancelled));
+        assert_eq!(manager.state.lock().unwrap().active, 0);
+    }
+
+    #[test]
+    fn stale_queue_entries() {
+        let manager = DownloadManager::builder().with_max_active(1).build();
+        let (_, _first_slot) = manager.register(1, 1);
+        let (_, _cancelled_slot) = manager.register(2, 32);
+        assert!(!manager.finish(2, DownloadStatus::Cancelled));
+        let (second, second_slot) = manager.register(2, 1);
+        let (third, third_slot) = manager.register(3, 16);
+        let (mut second_slot, mut third_slot) = (second_slot.unwrap(), third_slot.unwrap());
+
+        manager.apply(&update_file(1, false, true));
+        assert!(third_slot.try_recv().is_ok());
+        assert!(matches!(third.progress().status(), DownloadStatus::Active));
+        assert!(second_slot.try_recv().is_err());
+        assert!(matches!(second.progress().status(), DownloadStatus::Queued));
+    }
+
+    fn client(api: &MockedRawApi, max_active: usize) -> Client<MockedRawApi> {
+        let manager = DownloadManager::builder()
+            .with_max_active(max_active)
+            .build();
+        api.client_with(Client::builder().with_download_manager(manager))
+    }
+
+    // Waits until the response to DownloadFile is applied.
+    async fn started(download: &Download) {
+        let mut progress = download.progress_receiver();
+        progress
+            .wait_for(|progress| progress.downloaded_size() > 0)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_completed() {
+        let api = MockedRawApi::new(vec![update_file(1, true, false)["file"].clone()]);
+        let client = client(&api, 4);
+        let download = client.download(1, 16).unwrap();
+        started(&download).await;
+        client
+            .download_manager()
+            .unwrap()
+            .apply(&update_file(1, false, true));
+        assert_eq!(download.wait().await.unwrap(), "/tmp/file");
+        assert_eq!(api.request_types(), vec!["downloadFile"]);
+        assert_eq!(api.request_fields("priority"), vec![16]);
+    }
+
+    #[tokio::test]
+    async fn download_failed() {
+        let api = MockedRawApi::new(vec![error(400)]);
+        let client = client(&api, 4);
+        let download = client.download(1, 16).unwrap();
+        match download.wait().await {
+            Err(Error::TDLibError(err)) => assert_eq!(err.code(), 400),
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert!(client.download_manager().unwrap().pending().is_empty());
+    }
+
+    #[tokio::test]
+    async fn download_cancelled() {
+        let api = MockedRawApi::new(vec![update_file(1, true, false)["file"].clone(), ok()]);
+        let client = client(&api, 1);
+        let active = client.download(1, 16).unwrap();
+        let queued = client.download(2, 16).unwrap();
+        started(&active).await;
+
+        // queued download isn't known to TDLib yet
+        client.cancel_download(2).await.unwrap();
+        assert!(queued.wait().await.is_err());
+        assert_eq!(api.request_types(), vec!["downloadFile"]);
+
+        client.cancel_download(1).await.unwrap();
+        assert!(active.wait().await.is_err());
+        assert_eq!(
+            api.request_types(),
+            vec!["downloadFile", "cancelDownloadFile"]
+        );
+        assert_eq!(api.request_fields("file_id"), vec![1, 1]);
+        assert!(client.download_manager().unwrap().pending().is_empty());
+    }
+}
@@ -0,0 +1,703 @@
+//! Continuous reading of supergroup and channel [event logs](crate::types::GetChatEventLog).
+//!
+//! [Client::event_log_stream](crate::client::Client::event_log_stream) polls the event log and yields every event once, from older to newer.
+//! The identifier of the last consumed event can be kept in a file, so after restart the stream continues where it stopped.
+//! Events are delivered at least once: the cursor is written when the next portion of events is requested.
+//! ```no_run
+//! # use futures::StreamExt;
+//! # use rust_tdlib::client::{event_log::{AuditRecord, EventLogOptions}, Client, tdlib_client::TdJson};
+//! # use rust_tdlib::types::ChatEventLogFilters;
+//! # async fn run(client: Client<TdJson>, chat_id: i64) -> rust_tdlib::errors::Result<()> {
+//! let options = EventLogOptions::builder()
+//!     .with_filters(ChatEventLogFilters::builder().member_promotions(true).member_restrictions(true).build())
+//!     .with_poll_interval(30.)
+//!     .with_cursor_path("audit.cursor")
+//!     .build();
+//! let mut events = Box::pin(client.event_log_stream(chat_id, options));
+//! while let Some(event) = events.next().await {
+//!     let record = AuditRecord::from(&event?);
+//!     println!("{}", serde_json::to_string(&record)?);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use super::pagination::{paginate, Pager};
+use super::tdlib_client::TdLibClient;
+use super::{seconds, Client};
+use crate::errors::{Error, Result};
+use crate::types::{
+    ChatEvent, ChatEventAction, ChatEventLogFilters, GetChatEventLog, MessageSender,
+};
+use futures::stream::Stream;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// TDLib returns at most 100 events per request
+const MAX_PAGE_SIZE: usize = 100;
+// Shorter interval would flood TDLib with event log requests.
+const MIN_POLL_INTERVAL: f64 = 1.;
+const WORKER_NOT_BOUND: Error =
+    Error::BadRequest("event log polling requires the client bound with worker");
+const INVALID_CURSOR: Error = Error::Internal("invalid event log cursor file");
+
+/// Settings of [Client::event_log_stream](crate::client::Client::event_log_stream).
+#[derive(Debug, Clone)]
+pub struct EventLogOptions {
+    filters: ChatEventLogFilters,
+    query: String,
+    user_ids: Vec<i64>,
+    page_size: usize,
+    from_event_id: i64,
+    poll_interval: Option<Duration>,
+    cursor_path: Option<PathBuf>,
+}
+
+impl Default for EventLogOptions {
+    fn default() -> Self {
+        Self {
+            filters: ChatEventLogFilters::default(),
+            query: String::new(),
+            user_ids: vec![],
+            page_size: MAX_PAGE_SIZE,
+            from_event_id: 0,
+            poll_interval: None,
+            cursor_path: None,
+        }
+    }
+}
+
+impl EventLogOptions {
+    pub fn builder() -> EventLogOptionsBuilder {
+        EventLogOptionsBuilder::default()
+    }
+
+    pub fn filters(&self) -> &ChatEventLogFilters {
+        &self.filters
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn user_ids(&self) -> &[i64] {
+        &self.user_ids
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    pub fn from_event_id(&self) -> i64 {
+        self.from_event_id
+    }
+
+    pub fn poll_interval(&self) -> Option<Duration> {
+        self.poll_interval
+    }
+
+    pub fn cursor_path(&self) -> Option<&Path> {
+        self.cursor_path.as_deref()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct EventLogOptionsBuilder {
+    inner: EventLogOptions,
+}
+
+impl EventLogOptionsBuilder {
+    /// Types of events to return. By default, all types of events are returned.
+    pub fn with_filters<T: AsRef<ChatEventLogFilters>>(mut self, filters: T) -> Self {
+        self.inner.filters = filters.as_ref().clone();
+        self
+    }
+
+    /// Search query by which to filter events.
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.inner.query = query.as_ref().to_string();
+        self
+    }
+
+    /// Users whose events will be returned. By default, events relating to all users are returned.
+    pub fn with_user_ids(mut self, user_ids: Vec<i64>) -> Self {
+        self.inner.user_ids = user_ids;
+        self
+    }
+
+    /// Number of events requested at once. Default and maximum is 100.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.inner.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    /// Identifier of the last already processed event; only newer events are returned. Cursor file, if exists, takes precedence.
+    pub fn with_from_event_id(mut self, from_event_id: i64) -> Self {
+        self.inner.from_event_id = from_event_id;
+        self
+    }
+
+    /// Interval between requests for new events, in seconds. Without it the stream ends when there are no new events.
+    /// Interval is at least 1 second; NaN is ignored.
+    pub fn with_poll_interval(mut self, poll_interval: f64) -> Self {
+        if let Some(poll_interval) = seconds::duration(poll_interval, MIN_POLL_INTERVAL) {
+            self.inner.poll_interval = Some(poll_interval);
+        }
+        self
+    }
+
+    /// File where identifier of the last consumed event is kept.
+    pub fn with_cursor_path<P: AsRef<Path>>(mut self, cursor_path: P) -> Self {
+        self.inner.cursor_path = Some(cursor_path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn build(self) -> EventLogOptions {
+        self.inner
+    }
+}
+
+fn read_cursor(path: &Path) -> Result<Option<i64>> {
+    match fs::read_to_string(path) {
+        Ok(cursor) => cursor.trim().parse().map(Some).map_err(|_| INVALID_CURSOR),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn write_cursor(path: &Path, cursor: i64) -> Result<()> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    fs::write(&tmp_path, cursor.to_string())?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Returns events newer than the cursor, from older to newer, without duplicates.
+fn new_events(mut events: Vec<ChatEvent>, cursor: i64) -> Vec<ChatEvent> {
+    events.retain(|event| event.id() > cursor);
+    events.sort_by_key(|event| event.id());
+    events.dedup_by_key(|event| event.id());
+    events
+}
+
+struct EventLogState<'a, R>
+where
+    R: TdLibClient + Clone,
+{
+    client: &'a Client<R>,
+    chat_id: i64,
+    options: EventLogOptions,
+    // identifier of the newest returned event
+    cursor: Option<i64>,
+    saved_cursor: Option<i64>,
+    polled: bool,
+    done: bool,
+}
+
+impl<R> EventLogState<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    fn load_cursor(&mut self) -> Result<i64> {
+        if let Some(cursor) = self.cursor {
+            return Ok(cursor);
+        }
+        let saved = match &self.options.cursor_path {
+            Some(path) => read_cursor(path)?,
+            None => None,
+        };
+        let cursor = saved.unwrap_or(self.options.from_event_id);
+        self.cursor = Some(cursor);
+        self.saved_cursor = saved;
+        Ok(cursor)
+    }
+
+    fn save_cursor(&mut self) -> Result<()> {
+        if let (Some(path), Some(cursor)) = (&self.options.cursor_path, self.cursor) {
+            if self.saved_cursor != Some(cursor) {
+                write_cursor(path, cursor)?;
+                self.saved_cursor = Some(cursor);
+            }
+        }
+        Ok(())
+    }
+
+    /// Loads events from the newest one back to the cursor.
+    async fn fetch(&self, cursor: i64) -> Result<Vec<ChatEvent>> {
+        let mut events = vec![];
+        let mut from_event_id = 0;
+        loop {
+            let request = GetChatEventLog::builder()
+                .chat_id(self.chat_id)
+                .query(&self.options.query)
+                .from_event_id(from_event_id)
+                .limit(self.options.page_size as i32)
+                .filters(&self.options.filters)
+                .user_ids(self.options.user_ids.clone())
+                .build();
+            let page = self.client.get_chat_event_log(request).await?;
+            let page = page.events();
+            let oldest = page.iter().map(|event| event.id()).min();
+            events.extend(page.iter().cloned());
+            match oldest {
+                Some(oldest) if oldest > cursor && page.len() >= self.options.page_size => {
+                    from_event_id = oldest
+                }
+                _ => return Ok(new_events(events, cursor)),
+            }
+        }
+    }
+}
+
+impl<R> Pager for EventLogState<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    type Item = ChatEvent;
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    async fn next_page(&mut self) -> Result<Vec<ChatEvent>> {
+        // all events of the previous page are consumed at this point
+        self.save_cursor()?;
+        let cursor = self.load_cursor()?;
+        if self.polled {
+            if let Some(interval) = self.options.poll_interval {
+                let executor = self.client.executor().ok_or(WORKER_NOT_BOUND)?;
+                executor.sleep(interval).await;
+            }
+        }
+        self.polled = true;
+        let events = self.fetch(cursor).await?;
+        match events.last() {
+            Some(last) => self.cursor = Some(last.id()),
+            None if self.options.poll_interval.is_none() => self.done = true,
+            None => {}
+        }
+        Ok(events)
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Returns stream of new events of the supergroup or channel event log, from older to newer.
+    /// With [poll interval](crate::client::event_log::EventLogOptionsBuilder::with_poll_interval) the stream is endless, otherwise it ends when there are no new events.
+    /// The stream also ends after an error.
+    pub fn event_log_stream(
+        &self,
+        chat_id: i64,
+        options: EventLogOptions,
+    ) -> impl Stream<Item = Result<ChatEvent>> + '_ {
+        paginate(EventLogState {
+            client: self,
+            chat_id,
+            options,
+            cursor: None,
+            saved_cursor: None,
+            polled: false,
+            done: false,
+        })
+    }
+}
+
+/// Flat representation of a [ChatEvent](crate::types::ChatEvent), suitable for audit logs.
+/// Complex values, such as member statuses and permissions, are kept as JSON strings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    event_id: i64,
+    date: i32,
+    action: String,
+    actor_user_id: Option<i64>,
+    actor_chat_id: Option<i64>,
+    target_user_id: Option<i64>,
+    target_chat_id: Option<i64>,
+    approver_user_id: Option<i64>,
+    message_id: Option<i64>,
+    old_value: Option<String>,
+    new_value: Option<String>,
+}
+
+impl AuditRecord {
+    pub fn event_id(&self) -> i64 {
+        self.event_id
+    }
+
+    pub fn date(&self) -> i32 {
+        self.date
+    }
+
+    /// TDLib type of the action, for example `chatEventMemberPromoted`.
+    pub fn action(&self) -> &str {
+        &self.action
+    }
+
+    /// User who performed the action.
+    pub fn actor_user_id(&self) -> Option<i64> {
+        self.actor_user_id
+    }
+
+    /// Chat on behalf of which the action was performed.
+    pub fn actor_chat_id(&self) -> Option<i64> {
+        self.actor_chat_id
+    }
+
+    /// User affected by the action.
+    pub fn target_user_id(&self) -> Option<i64> {
+        self.target_user_id
+    }
+
+    /// Chat affected by the action.
+    pub fn target_chat_id(&self) -> Option<i64> {
+        self.target_chat_id
+    }
+
+    /// Administrator who approved the join request of the target.
+    pub fn approver_user_id(&self) -> Option<i64> {
+        self.approver_user_id
+    }
+
+    /// Message affected by the action.
+    pub fn message_id(&self) -> Option<i64> {
+        self.message_id
+    }
+
+    pub fn old_value(&self) -> Option<&str> {
+        self.old_value.as_deref()
+    }
+
+    pub fn new_value(&self) -> Option<&str> {
+        self.new_value.as_deref()
+    }
+
+    fn set_target(&mut self, target: &MessageSender) {
+        let (user_id, chat_id) = sender_ids(target);
+        self.target_user_id = user_id;
+        self.target_chat_id = chat_id;
+    }
+
+    fn set_values<T: ToString>(&mut self, old_value: T, new_value: T) {
+        self.old_value = Some(old_value.to_string());
+        self.new_value = Some(new_value.to_string());
+    }
+
+    fn set_json_values<T: serde::Serialize>(&mut self, old_value: &T, new_value: &T) {
+        self.old_value = json(old_value);
+        self.new_value = json(new_value);
+    }
+}
+
+fn sender_ids(sender: &MessageSender) -> (Option<i64>, Option<i64>) {
+    match sender {
+        MessageSender::User(user) => (Some(user.user_id()), None),
+        MessageSender::Chat(chat) => (None, Some(chat.chat_id())),
+        MessageSender::_Default => (None, None),
+    }
+}
+
+fn json<T: serde::Serialize>(value: &T) -> Option<String> {
+    serde_json::to_string(value).ok()
+}
+
+impl From<&ChatEvent> for AuditRecord {
+    fn from(event: &ChatEvent) -> Self {
+        let (actor_user_id, actor_chat_id) = sender_ids(event.member_id());
+        let mut record = AuditRecord {
+            event_id: event.id(),
+            date: event.date(),
+            actor_user_id,
+            actor_chat_id,
+            ..Default::default()
+        };
+        let action = match event.action() {
+            ChatEventAction::_Default => "",
+            ChatEventAction::ChatEventDescriptionChanged(action) => {
+                record.set_values(action.old_description(), action.new_description());
+                "chatEventDescriptionChanged"
+            }
+            ChatEventAction::ChatEventHasProtectedContentToggled(action) => {
+                record.new_value = Some(action.has_protected_content().to_string());
+                "chatEventHasProtectedContentToggled"
+            }
+            ChatEventAction::ChatEventInviteLinkDeleted(action) => {
+                record.old_value = Some(action.invite_link().invite_link().clone());
+                "chatEventInviteLinkDeleted"
+            }
+            ChatEventAction::ChatEventInviteLinkEdited(action) => {
+                record.set_json_values(action.old_invite_link(), action.new_invite_link());
+                "chatEventInviteLinkEdited"
+            }
+            ChatEventAction::ChatEventInviteLinkRevoked(action) => {
+                record.old_value = Some(action.invite_link().invite_link().clone());
+                "chatEventInviteLinkRevoked"
+            }
+            ChatEventAction::ChatEventInvitesToggled(action) => {
+                record.new_value = Some(action.can_invite_users().to_string());
+                "chatEventInvitesToggled"
+            }
+            ChatEventAction::ChatEventIsAllHistoryAvailableToggled(action) => {
+                record.new_value = Some(action.is_all_history_available().to_string());
+                "chatEventIsAllHistoryAvailableToggled"
+            }
+            ChatEventAction::ChatEventLinkedChatChanged(action) => {
+                record.set_values(action.old_linked_chat_id(), action.new_linked_chat_id());
+                "chatEventLinkedChatChanged"
+            }
+            ChatEventAction::ChatEventLocationChanged(action) => {
+                record.set_json_values(action.old_location(), action.new_location());
+                "chatEventLocationChanged"
+            }
+            ChatEventAction::ChatEventMemberInvited(action) => {
+                record.target_user_id = Some(action.user_id());
+                record.new_value = json(action.status());
+                "chatEventMemberInvited"
+            }
+            ChatEventAction::ChatEventMemberJoined(_) => "chatEventMemberJoined",
+            ChatEventAction::ChatEventMemberJoinedByInviteLink(action) => {
+                record.new_value = Some(action.invite_link().invite_link().clone());
+                "chatEventMemberJoinedByInviteLink"
+            }
+            ChatEventAction::ChatEventMemberJoinedByRequest(action) => {
+                record.set_target(event.member_id());
+                record.approver_user_id = Some(action.approver_user_id());
+                record.new_value = action
+                    .invite_link()
+                    .as_ref()
+                    .map(|link| link.invite_link().clone());
+                "chatEventMemberJoinedByRequest"
+            }
+            ChatEventAction::ChatEventMemberLeft(_) => "chatEventMemberLeft",
+            ChatEventAction::ChatEventMemberPromoted(action) => {
+                record.target_user_id = Some(action.user_id());
+                record.set_json_values(action.old_status(), action.new_status());
+                "chatEventMemberPromoted"
+            }
+            ChatEventAction::ChatEventMemberRestricted(action) => {
+                record.set_target(action.member_id());
+                record.set_json_values(action.old_status(), action.new_status());
+                "chatEventMemberRestricted"
+            }
+            ChatEventAction::ChatEventMessageDeleted(action) => {
+                record.message_id = Some(action.message().id());
+                record.old_value = json(action.message().content());
+                "chatEventMessageDeleted"
+            }
+            ChatEventAction::ChatEventMessageEdited(action) => {
+                record.message_id = Some(action.new_message().id());
+                record.set_json_values(
+                    action.old_message().content(),
+                    action.new_message().content(),
+                );
+                "chatEventMessageEdited"
+            }
+            ChatEventAction::ChatEventMessagePinned(action) => {
+                record.message_id = Some(action.message().id());
+                "chatEventMessagePinned"
+            }
+            ChatEventAction::ChatEventMessageTtlChanged(action) => {
+                record.set_values(action.old_message_ttl(), action.new_message_ttl());
+                "chatEventMessageTtlChanged"
+            }
+            ChatEventAction::ChatEventMessageUnpinned(action) => {
+                record.message_id = Some(action.message().id());
+                "chatEventMessageUnpinned"
+            }
+            ChatEventAction::ChatEventPermissionsChanged(action) => {
+                record.set_json_values(action.old_permissions(), action.new_permissions());
+                "chatEventPermissionsChanged"
+            }
+            ChatEventAction::ChatEventPhotoChanged(action) => {
+                record.set_json_values(action.old_photo(), action.new_photo());
+                "chatEventPhotoChanged"
+            }
+            ChatEventAction::ChatEventPollStopped(action) => {
+                record.message_id = Some(action.message().id());
+                "chatEventPollStopped"
+            }
+            ChatEventAction::ChatEventSignMessagesToggled(action) => {
+                record.new_value = Some(action.sign_messages().to_string());
+                "chatEventSignMessagesToggled"
+            }
+            ChatEventAction::ChatEventSlowModeDelayChanged(action) => {
+                record.set_values(action.old_slow_mode_delay(), action.new_slow_mode_delay());
+                "chatEventSlowModeDelayChanged"
+            }
+            ChatEventAction::ChatEventStickerSetChanged(action) => {
+                record.set_values(action.old_sticker_set_id(), action.new_sticker_set_id());
+                "chatEventStickerSetChanged"
+            }
+            ChatEventAction::ChatEventTitleChanged(action) => {
+                record.set_values(action.old_title(), action.new_title());
+                "chatEventTitleChanged"
+            }
+            ChatEventAction::ChatEventUsernameChanged(action) => {
+                record.set_values(action.old_username(), action.new_username());
+                "chatEventUsernameChanged"
+            }
+            ChatEventAction::ChatEventVideoChatCreated(action) => {
+                record.new_value = Some(action.group_call_id().to_string());
+                "chatEventVideoChatCreated"
+            }
+            ChatEventAction::ChatEventVideoChatEnded(action) => {
+                record.old_value = Some(action.group_call_id().to_string());
+                "chatEventVideoChatEnded"
+            }
+            ChatEventAction::ChatEventVideoChatMuteNewParticipantsToggled(action) => {
+                record.new_value = Some(action.mute_new_participants().to_string());
+                "chatEventVideoChatMuteNewParticipantsToggled"
+            }
+            ChatEventAction::ChatEventVideoChatParticipantIsMutedToggled(action) => {
+                record.set_target(action.participant_id());
+                record.new_value = Some(action.is_muted().to_string());
+                "chatEventVideoChatParticipantIsMutedToggled"
+            }
+            ChatEventAction::ChatEventVideoChatParticipantVolumeLevelChanged(action) => {
+                record.set_target(action.participant_id());
+                record.new_value = Some(action.volume_level().to_string());
+                "chatEventVideoChatParticipantVolumeLevelChanged"
+            }
+        };
+        record.action = action.to_string();
+        record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_events, AuditRecord, EventLogOptions};
+    use crate::client::mocked_raw_api::MockedRawApi;
+    use crate::errors::Result;
+    use crate::types::ChatEvent;
+    use futures::StreamExt;
+    use serde_json::{json, Value};
+    use std::fs;
+    use std::time::Duration;
+
+    fn event(id: i64, action: serde_json::Value) -> ChatEvent {
+        serde_json::from_value(json!({
+            "@type": "chatEvent",
+            "id": id.to_string(),
+            "date": 1_600_000_000,
+            "member_id": {"@type": "messageSenderUser", "user_id": 7},
+            "action": action,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn new_events_order() {
+        let left = json!({"@type": "chatEventMemberLeft"});
+        let events = vec![
+            event(5, left.clone()),
+            event(4, left.clone()),
+            event(5, left.clone()),
+            event(2, left),
+        ];
+        let ids: Vec<i64> = new_events(events, 2).iter().map(|e| e.id()).collect();
+        assert_eq!(ids, vec![4, 5]);
+    }
+
+    #[test]
+    fn audit_record() {
+        let record = AuditRecord::from(&event(
+            3,
+            json!({"@type": "chatEventTitleChanged", "old_title": "old", "new_title": "new"}),
+        ));
+        assert_eq!(record.event_id(), 3);
+        assert_eq!(record.action(), "chatEventTitleChanged");
+        assert_eq!(record.actor_user_id(), Some(7));
+        assert_eq!(record.old_value(), Some("old"));
+        assert_eq!(record.new_value(), Some("new"));
+    }
+
+    #[test]
+    fn member_joined_by_request() {
+        let record = AuditRecord::from(&event(
+            4,
+            json!({"@type": "chatEventMemberJoinedByRequest", "approver_user_id": 9}),
+        ));
+        assert_eq!(record.actor_user_id(), Some(7));
+        assert_eq!(record.target_user_id(), Some(7));
+        assert_eq!(record.approver_user_id(), Some(9));
+    }
+
+    #[test]
+    fn poll_interval() {
+        let options = EventLogOptions::builder().with_poll_interval(-1.).build();
+        assert_eq!(options.poll_interval(), Some(Duration::from_secs(1)));
+        let options = EventLogOptions::builder()
+            .with_poll_interval(f64::NAN)
+            .build();
+        assert_eq!(options.poll_interval(), None);
+    }
+    // Response to GetChatEventLog with member left events.
+    fn page(ids: &[i64]) -> Value {
+        let events: Vec<Value> = ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "@type": "chatEvent",
+                    "id": id.to_string(),
+                    "member_id": {"@type": "messageSenderUser", "user_id": 7},
+                    "action": {"@type": "chatEventMemberLeft"},
+                })
+            })
+            .collect();
+        json!({"@type": "chatEvents", "events": events})
+    }
+
+    async fn events(api: &MockedRawApi, options: EventLogOptions) -> Vec<Result<ChatEvent>> {
+        let client = api.client();
+        client.event_log_stream(1, options).collect().await
+    }
+
+    fn ids(events: Vec<Result<ChatEvent>>) -> Vec<i64> {
+        events
+            .into_iter()
+            .map(|event| event.unwrap().id())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn event_log_pages() {
+        let api = MockedRawApi::new(vec![page(&[5, 4]), page(&[4, 3]), page(&[2]), page(&[])]);
+        let options = EventLogOptions::builder()
+            .with_page_size(2)
+            .with_from_event_id(2)
+            .build();
+        assert_eq!(ids(events(&api, options).await), vec![3, 4, 5]);
+        assert_eq!(
+            api.request_fields("from_event_id"),
+            vec![json!("0"), json!("4"), json!("3"), json!("0")]
+        );
+    }
+
+    #[tokio::test]
+    async fn event_log_cursor() {
+        let path = std::env::temp_dir().join(format!("event_log_cursor_{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let options = EventLogOptions::builder()
+            .with_cursor_path(&path)
+            .with_from_event_id(1)
+            .build();
+
+        let api = MockedRawApi::new(vec![page(&[3, 2, 1]), page(&[])]);
+        assert_eq!(ids(events(&api, options.clone()).await), vec![2, 3]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3");
+
+        // the cursor file takes precedence over the identifier of the options
+        let api = MockedRawApi::new(vec![page(&[4, 3, 2]), page(&[])]);
+        assert_eq!(ids(events(&api, options.clone()).await), vec![4]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "4");
+
+        fs::write(&path, "invalid").unwrap();
+        let api = MockedRawApi::new(vec![]);
+        assert!(events(&api, options).await[0].is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
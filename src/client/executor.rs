@@ -0,0 +1,218 @@
+//! Runtime abstraction used by [Worker](crate::client::worker::Worker) and [ProxyManager](crate::client::proxy::ProxyManager).
+//!
+//! Worker needs only three things from an async runtime: spawning of tasks, running of blocking code and timers.
+//! Channels and locks come from [tokio::sync](tokio::sync), which doesn't depend on tokio runtime, so they work with any executor.
+//! [TokioExecutor](crate::client::executor::TokioExecutor) is used by default if `tokio-runtime` feature enabled (it is by default);
+//! to run the client on another runtime implement [Executor](crate::client::executor::Executor) and pass it to [WorkerBuilder::with_executor](crate::client::worker::WorkerBuilder::with_executor).
+//! ```ignore
+//! #[derive(Debug)]
+//! struct AsyncStdExecutor;
+//!
+//! impl Executor for AsyncStdExecutor {
+//!     fn spawn(&self, future: BoxFuture<'static, ()>) {
+//!         async_std::task::spawn(future);
+//!     }
+//!
+//!     fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+//!         Box::pin(async_std::task::spawn_blocking(f))
+//!     }
+//!
+//!     fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+//!         Box::pin(async_std::task::sleep(duration))
+//!     }
+//! }
+//! ```
+use crate::errors::{Error, Result, CLOSED_CHANNEL_ERROR, SEND_TO_CHANNEL_TIMEOUT};
+use futures::channel::oneshot;
+pub use futures::future::BoxFuture;
+use futures::future::{self, AbortHandle, Either, FutureExt};
+use std::any::Any;
+use std::fmt::Debug;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+const TASK_ABORTED: Error = Error::Internal("task aborted");
+const TASK_PANICKED: Error = Error::Internal("task panicked");
+
+/// Minimal interface of an async runtime.
+pub trait Executor: Debug + Send + Sync + 'static {
+    /// Runs the future in background. Executor must not cancel it until completion.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+
+    /// Runs blocking function on a thread where blocking is acceptable and resolves when the function returns.
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()>;
+
+    /// Resolves after specified duration.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// [Executor](crate::client::executor::Executor) backed by tokio runtime. Methods must be called within tokio runtime context.
+#[cfg(feature = "tokio-runtime")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioExecutor;
+
+#[cfg(feature = "tokio-runtime")]
+impl Executor for TokioExecutor {
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        tokio::spawn(future);
+    }
+
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+        let handle = tokio::task::spawn_blocking(f);
+        Box::pin(async move {
+            if let Err(err) = handle.await {
+                log::error!("blocking task failed: {}", err);
+            }
+        })
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// Returns default executor, if any.
+pub(crate) fn default_executor() -> Option<Arc<dyn Executor>> {
+    #[cfg(feature = "tokio-runtime")]
+    return Some(Arc::new(TokioExecutor));
+    #[cfg(not(feature = "tokio-runtime"))]
+    return None;
+}
+
+/// Handle of a task spawned by the worker. Resolves when the task completes; returns error if the task was aborted or panicked.
+#[derive(Debug)]
+pub struct TaskHandle {
+    abort_handle: AbortHandle,
+    // error if the task panicked; dropped sender means that the task was aborted
+    done: oneshot::Receiver<Result<()>>,
+}
+
+impl TaskHandle {
+    /// Cancels the task.
+    pub fn abort(&self) {
+        self.abort_handle.abort()
+    }
+}
+
+impl Future for TaskHandle {
+    type Output = Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.done
+            .poll_unpin(cx)
+            .map(|done| done.unwrap_or(Err(TASK_ABORTED)))
+    }
+}
+
+fn panicked(payload: Box<dyn Any + Send>) -> Error {
+    let message = match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_default(),
+    };
+    log::error!("task panicked: {}", message);
+    TASK_PANICKED
+}
+
+pub(crate) fn spawn<F>(executor: &dyn Executor, future: F) -> TaskHandle
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let (future, abort_handle) = future::abortable(future);
+    let (sender, done) = oneshot::channel();
+    executor.spawn(Box::pin(async move {
+        match AssertUnwindSafe(future).catch_unwind().await {
+            Ok(Ok(())) => {
+                let _ = sender.send(Ok(()));
+            }
+            Ok(Err(_aborted)) => {}
+            Err(payload) => {
+                let _ = sender.send(Err(panicked(payload)));
+            }
+        }
+    }));
+    TaskHandle { abort_handle, done }
+}
+
+pub(crate) async fn spawn_blocking<F, T>(executor: &dyn Executor, f: F) -> Result<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let (sender, receiver) = oneshot::channel();
+    executor
+        .spawn_blocking(Box::new(move || {
+            let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(f)).map_err(panicked));
+        }))
+        .await;
+    receiver.await.unwrap_or(Err(TASK_ABORTED))
+}
+
+/// Returns `None` if the future didn't complete in time.
+pub(crate) async fn timeout<F: Future>(
+    executor: &dyn Executor,
+    duration: Duration,
+    future: F,
+) -> Option<F::Output> {
+    let future = Box::pin(future);
+    match future::select(future, executor.sleep(duration)).await {
+        Either::Left((output, _)) => Some(output),
+        Either::Right(_) => None,
+    }
+}
+
+pub(crate) async fn send_timeout<T>(
+    executor: &dyn Executor,
+    sender: &mpsc::Sender<T>,
+    value: T,
+    duration: Duration,
+) -> Result<()> {
+    match timeout(executor, duration, sender.send(value)).await {
+        None => Err(SEND_TO_CHANNEL_TIMEOUT),
+        Some(Err(_)) => Err(CLOSED_CHANNEL_ERROR),
+        Some(Ok(_)) => Ok(()),
+    }
+}
+
+#[cfg(all(test, feature = "tokio-runtime"))]
+mod tests {
+    use super::{send_timeout, spawn, spawn_blocking, timeout, TokioExecutor};
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn tokio_executor() {
+        let executor = TokioExecutor;
+        assert_eq!(spawn_blocking(&executor, || 42).await.unwrap(), 42);
+        assert!(spawn(&executor, async {}).await.is_ok());
+
+        let handle = spawn(&executor, futures::future::pending());
+        handle.abort();
+        assert_eq!(handle.await.unwrap_err().to_string(), "task aborted");
+
+        let handle = spawn(&executor, async { panic!("worker failed") });
+        assert_eq!(handle.await.unwrap_err().to_string(), "task panicked");
+        let result = spawn_blocking(&executor, || -> i32 { panic!("blocking failed") }).await;
+        assert_eq!(result.unwrap_err().to_string(), "task panicked");
+
+        let pending = futures::future::pending::<()>();
+        assert!(timeout(&executor, Duration::from_millis(1), pending)
+            .await
+            .is_none());
+
+        let (sx, _rx) = mpsc::channel(1);
+        send_timeout(&executor, &sx, 1, Duration::from_millis(1))
+            .await
+            .unwrap();
+        assert!(send_timeout(&executor, &sx, 2, Duration::from_millis(1))
+            .await
+            .is_err());
+    }
+}
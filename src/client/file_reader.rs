@@ -0,0 +1,502 @@
+use super::seconds;
+use super::tdlib_client::TdLibClient;
+use super::transfer::file_size;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{DownloadFile, File, GetFile, GetFileDownloadedPrefixSize, ReadFilePart};
+use base64::Engine;
+use futures::future::BoxFuture;
+use futures::io::{AsyncRead, AsyncSeek, SeekFrom};
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+const WORKER_NOT_BOUND: Error =
+    Error::BadRequest("file reader requires the client bound with worker");
+const DOWNLOAD_STOPPED: Error = Error::Internal("file download stopped");
+// shorter interval would flood TDLib with GetFileDownloadedPrefixSize requests
+const MIN_POLL_INTERVAL: f64 = 0.01;
+
+// TDLib accepts only 32-bit offsets in file requests
+fn tdlib_offset(offset: i64) -> Result<i32> {
+    i32::try_from(offset).map_err(|_| Error::Io(offset_error()))
+}
+
+fn offset_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "file offsets beyond 2 GiB aren't supported",
+    )
+}
+
+/// Settings of [FileReader](crate::client::file_reader::FileReader).
+#[derive(Debug, Clone)]
+pub struct FileReaderOptions {
+    priority: i32,
+    chunk_size: usize,
+    poll_interval: Duration,
+}
+
+impl Default for FileReaderOptions {
+    fn default() -> Self {
+        Self {
+            priority: 32,
+            chunk_size: 512 * 1024,
+            poll_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+impl FileReaderOptions {
+    pub fn builder() -> FileReaderOptionsBuilder {
+        FileReaderOptionsBuilder::default()
+    }
+
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FileReaderOptionsBuilder {
+    inner: FileReaderOptions,
+}
+
+impl FileReaderOptionsBuilder {
+    /// Priority of the download, from 1 to 32. Default is 32.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.inner.priority = priority.clamp(1, 32);
+        self
+    }
+
+    /// Maximum number of bytes read with one [ReadFilePart](crate::types::ReadFilePart) request. Default is 512 KiB.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.inner.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Interval, in seconds, between checks of downloaded part of the file while waiting for data. Default is 0.1.
+    /// Intervals shorter than 0.01 are raised to it; NaN is ignored.
+    pub fn with_poll_interval(mut self, poll_interval: f64) -> Self {
+        if let Some(poll_interval) = seconds::duration(poll_interval, MIN_POLL_INTERVAL) {
+            self.inner.poll_interval = poll_interval;
+        }
+        self
+    }
+
+    pub fn build(self) -> FileReaderOptions {
+        self.inner
+    }
+}
+
+// State of the reader which is moved into read request and returned back.
+struct ReaderState<R>
+where
+    R: TdLibClient + Clone,
+{
+    client: Client<R>,
+    file_id: i32,
+    options: FileReaderOptions,
+    // file size or its expected size; 0 if unknown
+    size: i64,
+    // offset of the last started download
+    download_offset: Option<i64>,
+    // download must be restarted from the new position if needed data isn't downloaded yet
+    seeked: bool,
+}
+
+impl<R> ReaderState<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    async fn start_download(&mut self, offset: i64) -> Result<()> {
+        let request = DownloadFile::builder()
+            .file_id(self.file_id)
+            .priority(self.options.priority)
+            .offset(tdlib_offset(offset)?)
+            .build();
+        self.update_size(&self.client.download_file(request).await?);
+        self.download_offset = Some(offset);
+        self.seeked = false;
+        Ok(())
+    }
+
+    fn update_size(&mut self, file: &File) {
+        self.size = file_size(file);
+    }
+
+    /// Returns number of downloaded bytes from the offset, waiting until some of them are available; 0 means end of the file.
+    async fn wait_available(&mut self, offset: i64) -> Result<i64> {
+        loop {
+            let request = GetFileDownloadedPrefixSize::builder()
+                .file_id(self.file_id)
+                .offset(tdlib_offset(offset)?)
+                .build();
+            let available = self
+                .client
+                .get_file_downloaded_prefix_size(request)
+                .await?
+                .count() as i64;
+            if available > 0 {
+                return Ok(available);
+            }
+            let restart = self.seeked || self.download_offset.is_none_or(|start| offset < start);
+            if restart {
+                self.start_download(offset).await?;
+                continue;
+            }
+            let file = self
+                .client
+                .get_file(GetFile::builder().file_id(self.file_id).build())
+                .await?;
+            self.update_size(&file);
+            let local = file.local();
+            if local.is_downloading_completed() {
+                return Ok(0);
+            }
+            if !local.is_downloading_active() {
+                return Err(DOWNLOAD_STOPPED);
+            }
+            let executor = self.client.executor().ok_or(WORKER_NOT_BOUND)?;
+            executor.sleep(self.options.poll_interval).await;
+        }
+    }
+
+    async fn read(&mut self, offset: i64, len: usize) -> Result<Vec<u8>> {
+        if self.size > 0 && offset >= self.size {
+            return Ok(vec![]);
+        }
+        let available = self.wait_available(offset).await?;
+        let count = available.min(len.min(self.options.chunk_size) as i64);
+        if count == 0 {
+            return Ok(vec![]);
+        }
+        let request = ReadFilePart::builder()
+            .file_id(self.file_id)
+            .offset(tdlib_offset(offset)?)
+            .count(tdlib_offset(count)?)
+            .build();
+        let part = self.client.read_file_part(request).await?;
+        base64::engine::general_purpose::STANDARD
+            .decode(part.data())
+            .map_err(|_| Error::Internal("invalid file part data"))
+    }
+}
+
+type ReadFuture<R> = BoxFuture<'static, (ReaderState<R>, Result<Vec<u8>>)>;
+
+/// [AsyncRead](futures::io::AsyncRead) and [AsyncSeek](futures::io::AsyncSeek) access to a file, which is downloaded as it's read.
+/// Reading waits until requested bytes are downloaded; seeking to not downloaded part moves the download there.
+/// TDLib supports only 32-bit offsets, so reading beyond 2 GiB fails with [InvalidInput](std::io::ErrorKind::InvalidInput).
+pub struct FileReader<R>
+where
+    R: TdLibClient + Clone,
+{
+    file_id: i32,
+    size: i64,
+    // taken while reading is in progress
+    state: Option<ReaderState<R>>,
+    reading: Option<ReadFuture<R>>,
+    position: u64,
+}
+
+// fields are never pinned: the read future is boxed
+impl<R> Unpin for FileReader<R> where R: TdLibClient + Clone {}
+
+impl<R> FileReader<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    pub fn new(client: &Client<R>, file: &File, options: FileReaderOptions) -> Self {
+        let mut state = ReaderState {
+            client: client.clone(),
+            file_id: file.id(),
+            options,
+            size: 0,
+            download_offset: None,
+            seeked: false,
+        };
+        state.update_size(file);
+        Self {
+            file_id: file.id(),
+            size: state.size,
+            state: Some(state),
+            reading: None,
+            position: 0,
+        }
+    }
+
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    /// File size or its expected size; 0 if unknown.
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+}
+
+fn seek_position(position: u64, size: i64, pos: SeekFrom) -> io::Result<u64> {
+    let position = match pos {
+        SeekFrom::Start(offset) => Some(offset),
+        SeekFrom::Current(offset) => position.checked_add_signed(offset),
+        SeekFrom::End(_) if size <= 0 => {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "file size is unknown",
+            ))
+        }
+        SeekFrom::End(offset) => (size as u64).checked_add_signed(offset),
+    };
+    position.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid seek to a negative or overflowing position",
+        )
+    })
+}
+
+impl<R> AsyncRead for FileReader<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        if buf.is_empty() {
+            return Poll::Ready(Ok(0));
+        }
+        if self.reading.is_none() {
+            let offset = i64::try_from(self.position).map_err(|_| offset_error())?;
+            let mut state = self.state.take().expect("file reader state is lost");
+            let len = buf.len();
+            self.reading = Some(Box::pin(async move {
+                let result = state.read(offset, len).await;
+                (state, result)
+            }));
+        }
+        let (state, result) = match self.reading.as_mut().unwrap().as_mut().poll(cx) {
+            Poll::Pending => return Poll::Pending,
+            Poll::Ready(ready) => ready,
+        };
+        self.reading = None;
+        self.size = state.size;
+        self.state = Some(state);
+        let data = result.map_err(|err| match err {
+            Error::Io(err) => err,
+            err => io::Error::other(err),
+        })?;
+        // the buffer may be shorter than requested one if it's changed between polls
+        let len = data.len().min(buf.len());
+        buf[..len].copy_from_slice(&data[..len]);
+        self.position += len as u64;
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl<R> AsyncSeek for FileReader<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    fn poll_seek(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: SeekFrom,
+    ) -> Poll<io::Result<u64>> {
+        // pending read must return the state before the position is changed
+        if let Some(reading) = self.reading.as_mut() {
+            let (state, _) = match reading.as_mut().poll(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(ready) => ready,
+            };
+            self.reading = None;
+            self.size = state.size;
+            self.state = Some(state);
+        }
+        let position = seek_position(self.position, self.size(), pos)?;
+        if position != self.position {
+            self.position = position;
+            if let Some(state) = self.state.as_mut() {
+                state.seeked = true;
+            }
+        }
+        Poll::Ready(Ok(position))
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    /// Returns [FileReader](crate::client::file_reader::FileReader) of the file, which reads the file while it's downloaded.
+    /// ```no_run
+    /// # use futures::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+    /// # use rust_tdlib::client::{file_reader::FileReaderOptions, Client, tdlib_client::TdJson};
+    /// # async fn run(client: Client<TdJson>, file: rust_tdlib::types::File) -> std::io::Result<()> {
+    /// let mut reader = client.file_reader(&file, FileReaderOptions::default());
+    /// reader.seek(SeekFrom::Start(1024 * 1024)).await?;
+    /// let mut chunk = vec![0; 64 * 1024];
+    /// let read = reader.read(&mut chunk).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn file_reader(&self, file: &File, options: FileReaderOptions) -> FileReader<R> {
+        FileReader::new(self, file, options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{seek_position, FileReaderOptions};
+    use crate::client::mocked_raw_api::MockedRawApi;
+    use crate::types::File;
+    use base64::Engine;
+    use futures::io::{AsyncReadExt, AsyncSeekExt, SeekFrom};
+    use serde_json::{json, Value};
+    use std::io::ErrorKind;
+    use std::time::Duration;
+
+    fn file(size: i64) -> Value {
+        json!({
+            "@type": "file",
+            "id": 1,
+            "size": size,
+            "local": {"@type": "localFile", "path": "/tmp/file", "is_downloading_active": true},
+            "remote": {"@type": "remoteFile", "id": "remote", "unique_id": "unique"},
+        })
+    }
+
+    fn prefix_size(count: i64) -> Value {
+        json!({"@type": "fileDownloadedPrefixSize", "count": count})
+    }
+
+    fn file_part(data: &[u8]) -> Value {
+        json!({
+            "@type": "filePart",
+            "data": base64::engine::general_purpose::STANDARD.encode(data),
+        })
+    }
+
+    #[test]
+    fn seek() {
+        assert_eq!(seek_position(10, 0, SeekFrom::Start(5)).unwrap(), 5);
+        assert_eq!(seek_position(10, 0, SeekFrom::Current(-4)).unwrap(), 6);
+        assert_eq!(seek_position(10, 100, SeekFrom::End(-1)).unwrap(), 99);
+        assert!(seek_position(10, 0, SeekFrom::End(0)).is_err());
+        assert!(seek_position(10, 0, SeekFrom::Current(-11)).is_err());
+    }
+
+    #[tokio::test]
+    async fn large_offset() {
+        let api = MockedRawApi::new(vec![]);
+        let client = api.client();
+        let file: File = serde_json::from_value(serde_json::json!({
+            "@type": "file",
+            "id": 1,
+            "local": {"@type": "localFile", "path": ""},
+            "remote": {"@type": "remoteFile", "id": "", "unique_id": ""},
+        }))
+        .unwrap();
+        let mut reader = client.file_reader(&file, FileReaderOptions::default());
+        reader.seek(SeekFrom::Start(3 << 30)).await.unwrap();
+        let err = reader.read(&mut [0; 16]).await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(api.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn read() {
+        let api = MockedRawApi::new(vec![
+            prefix_size(0),
+            file(10),
+            prefix_size(10),
+            file_part(b"0123"),
+        ]);
+        let client = api.client();
+        let file: File = serde_json::from_value(file(10)).unwrap();
+        let mut reader = client.file_reader(&file, FileReaderOptions::default());
+        let mut buf = [0; 4];
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 4);
+        assert_eq!(&buf, b"0123");
+        assert_eq!(reader.position(), 4);
+        assert_eq!(
+            api.request_types(),
+            vec![
+                "getFileDownloadedPrefixSize",
+                "downloadFile",
+                "getFileDownloadedPrefixSize",
+                "readFilePart",
+            ]
+        );
+        assert_eq!(api.requests()[3]["count"], 4);
+    }
+
+    #[tokio::test]
+    async fn seek_restarts_download() {
+        let api = MockedRawApi::new(vec![
+            prefix_size(0),
+            file(100),
+            prefix_size(10),
+            file_part(b"0123"),
+            prefix_size(0),
+            file(100),
+            prefix_size(5),
+            file_part(b"56789"),
+        ]);
+        let client = api.client();
+        let file: File = serde_json::from_value(file(100)).unwrap();
+        let mut reader = client.file_reader(&file, FileReaderOptions::default());
+        let mut buf = [0; 4];
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 4);
+
+        // position past the downloaded prefix
+        reader.seek(SeekFrom::Start(50)).await.unwrap();
+        let mut buf = [0; 8];
+        assert_eq!(reader.read(&mut buf).await.unwrap(), 5);
+        assert_eq!(&buf[..5], b"56789");
+        assert_eq!(reader.position(), 55);
+        let downloads: Vec<_> = api
+            .requests()
+            .into_iter()
+            .filter(|request| request["@type"] == "downloadFile")
+            .map(|request| request["offset"].clone())
+            .collect();
+        assert_eq!(downloads, vec![0, 50]);
+    }
+
+    #[tokio::test]
+    async fn end_of_file() {
+        let api = MockedRawApi::new(vec![]);
+        let client = api.client();
+        let file: File = serde_json::from_value(file(10)).unwrap();
+        let mut reader = client.file_reader(&file, FileReaderOptions::default());
+        reader.seek(SeekFrom::End(0)).await.unwrap();
+        assert_eq!(reader.read(&mut [0; 4]).await.unwrap(), 0);
+        assert!(api.requests().is_empty());
+    }
+
+    #[test]
+    fn poll_interval() {
+        let options = FileReaderOptions::builder().with_poll_interval(-1.).build();
+        assert_eq!(options.poll_interval(), Duration::from_millis(10));
+        let options = FileReaderOptions::builder()
+            .with_poll_interval(f64::NAN)
+            .build();
+        assert_eq!(options.poll_interval(), Duration::from_millis(100));
+    }
+}
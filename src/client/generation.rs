@@ -0,0 +1,378 @@
+//! Generation of files, which are sent with [InputFileGenerated](crate::types::InputFileGenerated).
+//!
+//! TDLib asks the application to generate a file with [UpdateFileGenerationStart](crate::types::UpdateFileGenerationStart).
+//! [FileGenerators](crate::client::generation::FileGenerators) calls the [FileGenerator](crate::client::generation::FileGenerator) registered for the conversion of the file
+//! and finishes the generation with its result. Conversions without registered generators are left to the application.
+//! ```no_run
+//! # use async_trait::async_trait;
+//! # use rust_tdlib::client::generation::{FileGeneration, FileGenerator, FileGenerators};
+//! #[derive(Debug)]
+//! struct Uppercase;
+//!
+//! #[async_trait]
+//! impl FileGenerator for Uppercase {
+//!     async fn generate(&self, generation: FileGeneration) -> rust_tdlib::errors::Result<()> {
+//!         let data = std::fs::read_to_string(generation.original_path())?.to_uppercase();
+//!         generation.set_progress(data.len() as i64, 0).await?;
+//!         generation.write_part(0, data.as_bytes()).await
+//!     }
+//! }
+//!
+//! let file_generators = FileGenerators::new();
+//! // files sent with conversion "uppercase" or "uppercase:<parameters>" are generated by `Uppercase`
+//! file_generators.register("uppercase", Uppercase);
+//! // the client is built with `.with_file_generators(file_generators)`
+//! ```
+use super::executor::Executor;
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{
+    Error as TDLibError, FinishFileGeneration, SetFileGenerationProgress, Update,
+    UpdateFileGenerationStart, WriteGeneratedFilePart,
+};
+use async_trait::async_trait;
+use base64::Engine;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::sync::watch;
+
+const GENERATION_CANCELLED: Error = Error::Internal("file generation cancelled");
+// TDLib accepts only 32-bit offsets and sizes of generated file parts
+const SIZE_TOO_LARGE: Error =
+    Error::BadRequest("generated file offset or size doesn't fit into 32 bits");
+// separates conversion name from its parameters
+const PARAMETERS_SEPARATOR: char = ':';
+
+/// Generates files with particular conversion.
+#[async_trait]
+pub trait FileGenerator: Debug + Send + Sync {
+    /// Generates the file by writing it with [FileGeneration::write_part](crate::client::generation::FileGeneration::write_part)
+    /// or directly to [destination path](crate::client::generation::FileGeneration::destination_path).
+    /// Returned error fails the generation; the result is ignored if the generation was cancelled.
+    async fn generate(&self, generation: FileGeneration) -> Result<()>;
+}
+
+// Requests made during file generation; allows generators to be independent of TDLib client type.
+#[async_trait]
+pub(crate) trait GenerationClient: Send + Sync {
+    async fn write_generated_file_part(&self, request: WriteGeneratedFilePart) -> Result<()>;
+
+    async fn set_file_generation_progress(&self, request: SetFileGenerationProgress) -> Result<()>;
+
+    async fn finish_file_generation(&self, request: FinishFileGeneration) -> Result<()>;
+}
+
+#[async_trait]
+impl<R> GenerationClient for Client<R>
+where
+    R: TdLibClient + Clone + Send + Sync + 'static,
+{
+    async fn write_generated_file_part(&self, request: WriteGeneratedFilePart) -> Result<()> {
+        self.write_generated_file_part(request).await.map(|_| ())
+    }
+
+    async fn set_file_generation_progress(&self, request: SetFileGenerationProgress) -> Result<()> {
+        self.set_file_generation_progress(request).await.map(|_| ())
+    }
+
+    async fn finish_file_generation(&self, request: FinishFileGeneration) -> Result<()> {
+        self.finish_file_generation(request).await.map(|_| ())
+    }
+}
+
+/// File generation process started by TDLib.
+pub struct FileGeneration {
+    start: UpdateFileGenerationStart,
+    client: Arc<dyn GenerationClient>,
+    cancelled: watch::Receiver<bool>,
+}
+
+impl Debug for FileGeneration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileGeneration")
+            .field("start", &self.start)
+            .field("cancelled", &self.cancelled)
+            .finish()
+    }
+}
+
+impl FileGeneration {
+    pub fn generation_id(&self) -> i64 {
+        self.start.generation_id()
+    }
+
+    /// The path to a file from which a new file is generated; may be empty.
+    pub fn original_path(&self) -> &str {
+        self.start.original_path()
+    }
+
+    /// The path to a file that must be created and where the new file is generated.
+    pub fn destination_path(&self) -> &str {
+        self.start.destination_path()
+    }
+
+    /// Conversion of [InputFileGenerated](crate::types::InputFileGenerated), including its parameters.
+    pub fn conversion(&self) -> &str {
+        self.start.conversion()
+    }
+
+    /// Returns `true` if TDLib stopped the generation with [UpdateFileGenerationStop](crate::types::UpdateFileGenerationStop).
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.borrow()
+    }
+
+    /// Resolves when the generation is cancelled.
+    pub async fn cancelled(&self) {
+        let mut cancelled = self.cancelled.clone();
+        // error means that the generation is finished
+        let _ = cancelled.wait_for(|cancelled| *cancelled).await;
+    }
+
+    /// Writes part of the generated file with [WriteGeneratedFilePart](crate::types::WriteGeneratedFilePart).
+    /// Offsets beyond 2 GiB aren't supported.
+    pub async fn write_part(&self, offset: i64, data: &[u8]) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(GENERATION_CANCELLED);
+        }
+        let request = WriteGeneratedFilePart::builder()
+            .generation_id(self.generation_id())
+            .offset(i32::try_from(offset).map_err(|_| SIZE_TOO_LARGE)?)
+            .data(base64::engine::general_purpose::STANDARD.encode(data))
+            .build();
+        self.client.write_generated_file_part(request).await
+    }
+
+    /// Informs TDLib about progress of the generation with [SetFileGenerationProgress](crate::types::SetFileGenerationProgress);
+    /// `expected_size` is 0 if unknown; `local_prefix_size` beyond 2 GiB isn't supported.
+    pub async fn set_progress(&self, expected_size: i64, local_prefix_size: i64) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(GENERATION_CANCELLED);
+        }
+        let request = SetFileGenerationProgress::builder()
+            .generation_id(self.generation_id())
+            .expected_size(expected_size)
+            .local_prefix_size(i32::try_from(local_prefix_size).map_err(|_| SIZE_TOO_LARGE)?)
+            .build();
+        self.client.set_file_generation_progress(request).await
+    }
+}
+
+/// Generators registered by conversion name, and generations in progress.
+#[derive(Debug, Clone, Default)]
+pub struct FileGenerators {
+    generators: Arc<RwLock<HashMap<String, Arc<dyn FileGenerator>>>>,
+    running: Arc<Mutex<HashMap<i64, watch::Sender<bool>>>>,
+}
+
+impl FileGenerators {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers generator for the conversion name. The generator is also used for conversions with parameters, which start with the name followed by `:`.
+    pub fn register<G: FileGenerator + 'static>(&self, conversion: &str, generator: G) {
+        self.generators
+            .write()
+            .unwrap()
+            .insert(conversion.to_string(), Arc::new(generator));
+    }
+
+    /// Returns generator for the conversion.
+    pub fn generator(&self, conversion: &str) -> Option<Arc<dyn FileGenerator>> {
+        let generators = self.generators.read().unwrap();
+        generators.get(conversion).cloned().or_else(|| {
+            let (name, _) = conversion.split_once(PARAMETERS_SEPARATOR)?;
+            generators.get(name).cloned()
+        })
+    }
+
+    /// Starts or cancels generation on TDLib update.
+    pub(crate) fn handle<R>(&self, client: &Client<R>, update: &Update, executor: &dyn Executor)
+    where
+        R: TdLibClient + Clone + Send + Sync + 'static,
+    {
+        match update {
+            Update::FileGenerationStart(start) => {
+                let generator = match self.generator(start.conversion()) {
+                    None => return,
+                    Some(generator) => generator,
+                };
+                let generation_id = start.generation_id();
+                let (cancel, cancelled) = watch::channel(false);
+                self.running.lock().unwrap().insert(generation_id, cancel);
+                let client: Arc<dyn GenerationClient> = Arc::new(client.clone());
+                let generation = FileGeneration {
+                    start: start.clone(),
+                    client: client.clone(),
+                    cancelled,
+                };
+                let running = self.running.clone();
+                executor.spawn(Box::pin(async move {
+                    let result = generator.generate(generation).await;
+                    // cancelled generation must not be finished
+                    if running.lock().unwrap().remove(&generation_id).is_none() {
+                        return;
+                    }
+                    let mut request = FinishFileGeneration::builder();
+                    request.generation_id(generation_id);
+                    if let Err(err) = result {
+                        log::warn!("file generation {} failed: {}", generation_id, err);
+                        request.error(TDLibError::builder().code(400).message(err.to_string()));
+                    }
+                    if let Err(err) = client.finish_file_generation(request.build()).await {
+                        log::error!("can't finish file generation {}: {}", generation_id, err);
+                    }
+                }));
+            }
+            Update::FileGenerationStop(stop) => {
+                if let Some(cancel) = self.running.lock().unwrap().remove(&stop.generation_id()) {
+                    cancel.send_replace(true);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FileGeneration, FileGenerator, FileGenerators};
+    use crate::client::executor::Executor;
+    use crate::client::mocked_raw_api::{ok, MockedRawApi};
+    use crate::errors::Result;
+    use crate::types::Update;
+    use crate::types::UpdateFileGenerationStart;
+    use async_trait::async_trait;
+    use futures::future::BoxFuture;
+    use serde_json::json;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    #[derive(Debug)]
+    struct Noop;
+
+    #[async_trait]
+    impl FileGenerator for Noop {
+        async fn generate(&self, _generation: FileGeneration) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    // Generator which runs until the generation is cancelled.
+    #[derive(Debug)]
+    struct UntilCancelled;
+
+    #[async_trait]
+    impl FileGenerator for UntilCancelled {
+        async fn generate(&self, generation: FileGeneration) -> Result<()> {
+            generation.cancelled().await;
+            Ok(())
+        }
+    }
+
+    // Executor which keeps spawned futures to run them in the test.
+    #[derive(Default)]
+    struct Spawned(Mutex<Vec<BoxFuture<'static, ()>>>);
+
+    impl fmt::Debug for Spawned {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("Spawned")
+        }
+    }
+
+    impl Spawned {
+        async fn run(&self) {
+            let futures: Vec<_> = self.0.lock().unwrap().drain(..).collect();
+            futures::future::join_all(futures).await;
+        }
+    }
+
+    impl Executor for Spawned {
+        fn spawn(&self, future: BoxFuture<'static, ()>) {
+            self.0.lock().unwrap().push(future);
+        }
+
+        fn spawn_blocking(&self, _f: Box<dyn FnOnce() + Send>) -> BoxFuture<'static, ()> {
+            Box::pin(async {})
+        }
+
+        fn sleep(&self, _duration: Duration) -> BoxFuture<'static, ()> {
+            Box::pin(async {})
+        }
+    }
+
+    fn start(generation_id: i64, conversion: &str) -> Update {
+        serde_json::from_value(json!({
+            "@type": "updateFileGenerationStart",
+            "generation_id": generation_id.to_string(),
+            "destination_path": "/tmp/generated",
+            "conversion": conversion,
+        }))
+        .unwrap()
+    }
+
+    fn stop(generation_id: i64) -> Update {
+        serde_json::from_value(json!({
+            "@type": "updateFileGenerationStop",
+            "generation_id": generation_id.to_string(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn generator_lookup() {
+        let generators = FileGenerators::new();
+        generators.register("thumbnail", Noop);
+        assert!(generators.generator("thumbnail").is_some());
+        assert!(generators.generator("thumbnail:320x320").is_some());
+        assert!(generators.generator("thumbnails").is_none());
+        assert!(generators.generator("#url#").is_none());
+    }
+
+    #[tokio::test]
+    async fn finish_generation() {
+        let api = MockedRawApi::new(vec![ok()]);
+        let client = api.client();
+        let executor = Spawned::default();
+        let generators = FileGenerators::new();
+        generators.register("noop", Noop);
+        generators.handle(&client, &start(1, "noop"), &executor);
+        executor.run().await;
+        assert_eq!(api.request_types(), vec!["finishFileGeneration"]);
+    }
+
+    #[tokio::test]
+    async fn cancelled_generation_not_finished() {
+        let api = MockedRawApi::new(vec![ok()]);
+        let client = api.client();
+        let executor = Spawned::default();
+        let generators = FileGenerators::new();
+        generators.register("wait", UntilCancelled);
+        generators.handle(&client, &start(2, "wait"), &executor);
+        generators.handle(&client, &stop(2), &executor);
+        executor.run().await;
+        assert!(api.request_types().is_empty());
+    }
+
+    #[tokio::test]
+    async fn large_offset() {
+        let api = MockedRawApi::new(vec![]);
+        let start: UpdateFileGenerationStart = match start(3, "noop") {
+            Update::FileGenerationStart(start) => start,
+            _ => unreachable!(),
+        };
+        let (_cancel, cancelled) = tokio::sync::watch::channel(false);
+        let generation = FileGeneration {
+            start,
+            client: Arc::new(api.client()),
+            cancelled,
+        };
+        let offset = i64::from(i32::MAX) + 1;
+        assert!(generation.write_part(offset, b"data").await.is_err());
+        assert!(generation.set_progress(offset, offset).await.is_err());
+        assert!(api.request_types().is_empty());
+    }
+}
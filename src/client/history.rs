@@ -0,0 +1,332 @@
+use super::pagination::{paginate, Pager};
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{GetChatHistory, Message};
+use futures::stream::Stream;
+
+// TDLib returns at most 100 messages per request
+const MAX_PAGE_SIZE: usize = 100;
+const FORWARD_WITHOUT_START: Error =
+    Error::BadRequest("forward history pagination requires non-zero from_message_id");
+
+/// Direction of [Client::history_stream](crate::client::Client::history_stream).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryDirection {
+    /// From newer messages to older ones.
+    Backward,
+    /// From older messages to newer ones.
+    Forward,
+}
+
+/// Settings of [Client::history_stream](crate::client::Client::history_stream).
+#[derive(Debug, Clone)]
+pub struct HistoryOptions {
+    from_message_id: i64,
+    direction: HistoryDirection,
+    page_size: usize,
+    only_local: bool,
+    stop_date: Option<i32>,
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        Self {
+            from_message_id: 0,
+            direction: HistoryDirection::Backward,
+            page_size: MAX_PAGE_SIZE,
+            only_local: false,
+            stop_date: None,
+        }
+    }
+}
+
+impl HistoryOptions {
+    pub fn builder() -> HistoryOptionsBuilder {
+        HistoryOptionsBuilder::default()
+    }
+
+    pub fn from_message_id(&self) -> i64 {
+        self.from_message_id
+    }
+
+    pub fn direction(&self) -> HistoryDirection {
+        self.direction
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    pub fn only_local(&self) -> bool {
+        self.only_local
+    }
+
+    pub fn stop_date(&self) -> Option<i32> {
+        self.stop_date
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct HistoryOptionsBuilder {
+    inner: HistoryOptions,
+}
+
+impl HistoryOptionsBuilder {
+    /// Message to start from; it's included in results. Default is 0, which means the last message of the chat; such start is allowed only for backward direction.
+    pub fn with_from_message_id(mut self, from_message_id: i64) -> Self {
+        self.inner.from_message_id = from_message_id;
+        self
+    }
+
+    /// Default is [HistoryDirection::Backward](crate::client::history::HistoryDirection::Backward).
+    pub fn with_direction(mut self, direction: HistoryDirection) -> Self {
+        self.inner.direction = direction;
+        self
+    }
+
+    /// Number of messages requested at once. Default and maximum is 100.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.inner.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    /// Return only messages available without network requests.
+    pub fn with_only_local(mut self, only_local: bool) -> Self {
+        self.inner.only_local = only_local;
+        self
+    }
+
+    /// Unix time at which the stream stops: messages sent before it for backward direction or after it for forward direction are not returned.
+    pub fn with_stop_date(mut self, stop_date: i32) -> Self {
+        self.inner.stop_date = Some(stop_date);
+        self
+    }
+
+    pub fn build(self) -> HistoryOptions {
+        self.inner
+    }
+}
+
+struct HistoryState<'a, R>
+where
+    R: TdLibClient + Clone,
+{
+    client: &'a Client<R>,
+    chat_id: i64,
+    options: HistoryOptions,
+    // identifier of the last returned message; messages up to it, including, are skipped
+    last_message_id: Option<i64>,
+    done: bool,
+}
+
+impl<R> HistoryState<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    fn request(&self) -> GetChatHistory {
+        let from_message_id = self.last_message_id.unwrap_or(self.options.from_message_id);
+        let mut request = GetChatHistory::builder();
+        request
+            .chat_id(self.chat_id)
+            .from_message_id(from_message_id)
+            .only_local(self.options.only_local);
+        match self.options.direction {
+            HistoryDirection::Backward => request.limit(self.options.page_size as i32),
+            // offset must be greater than -limit, and one message of the page is the starting one
+            HistoryDirection::Forward => {
+                let limit = self.options.page_size.max(2) as i32;
+                request.limit(limit).offset(-(limit - 1))
+            }
+        };
+        request.build()
+    }
+
+    fn is_new(&self, message: &Message) -> bool {
+        let message_id = message.id();
+        let from_message_id = self.options.from_message_id;
+        match (self.options.direction, self.last_message_id) {
+            (HistoryDirection::Backward, Some(last)) => message_id < last,
+            (HistoryDirection::Forward, Some(last)) => message_id > last,
+            (HistoryDirection::Backward, None) => {
+                from_message_id == 0 || message_id <= from_message_id
+            }
+            (HistoryDirection::Forward, None) => message_id >= from_message_id,
+        }
+    }
+
+    fn is_after_stop(&self, message: &Message) -> bool {
+        match (self.options.direction, self.options.stop_date) {
+            (HistoryDirection::Backward, Some(stop_date)) => message.date() < stop_date,
+            (HistoryDirection::Forward, Some(stop_date)) => message.date() > stop_date,
+            (_, None) => false,
+        }
+    }
+}
+
+impl<R> Pager for HistoryState<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    type Item = Message;
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    async fn next_page(&mut self) -> Result<Vec<Message>> {
+        if self.options.direction == HistoryDirection::Forward && self.options.from_message_id == 0
+        {
+            return Err(FORWARD_WITHOUT_START);
+        }
+        let messages = self.client.get_chat_history(self.request()).await?;
+        let mut page: Vec<Message> = messages
+            .messages()
+            .iter()
+            .flatten()
+            .filter(|message| self.is_new(message))
+            .cloned()
+            .collect();
+        // TDLib returns messages from newer to older
+        page.sort_by_key(|message| message.id());
+        if self.options.direction == HistoryDirection::Backward {
+            page.reverse();
+        }
+        // empty page means that the start or the end of the chat is reached
+        if page.is_empty() {
+            self.done = true;
+        }
+        if let Some(stop) = page.iter().position(|message| self.is_after_stop(message)) {
+            page.truncate(stop);
+            self.done = true;
+        }
+        if let Some(last) = page.last() {
+            self.last_message_id = Some(last.id());
+        }
+        Ok(page)
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Returns stream of messages of the chat, loaded with [GetChatHistory](crate::types::GetChatHistory) page by page.
+    /// TDLib may return fewer messages than requested, so the stream ends only when there are no more messages in chosen direction,
+    /// when [stop date](crate::client::history::HistoryOptionsBuilder::with_stop_date) is reached or after an error.
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use rust_tdlib::client::{history::HistoryOptions, Client, tdlib_client::TdJson};
+    /// # async fn run(client: Client<TdJson>, chat_id: i64) -> rust_tdlib::errors::Result<()> {
+    /// let options = HistoryOptions::builder().with_stop_date(1_600_000_000).build();
+    /// let mut messages = Box::pin(client.history_stream(chat_id, options));
+    /// while let Some(message) = messages.next().await {
+    ///     println!("{}", message?.id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn history_stream(
+        &self,
+        chat_id: i64,
+        options: HistoryOptions,
+    ) -> impl Stream<Item = Result<Message>> + '_ {
+        let state = HistoryState {
+            client: self,
+            chat_id,
+            options,
+            last_message_id: None,
+            done: false,
+        };
+        paginate(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HistoryDirection, HistoryOptions};
+    use crate::client::mocked_raw_api::{message, messages, MockedRawApi};
+    use crate::errors::Result;
+    use crate::types::Message;
+    use futures::StreamExt;
+    use serde_json::Value;
+
+    // Page of messages from newer to older, as TDLib returns them; message date is equal to its identifier.
+    fn page(ids: &[i64]) -> Value {
+        messages(ids.iter().map(|id| message(1, *id, *id as i32)).collect())
+    }
+
+    async fn history(api: &MockedRawApi, options: HistoryOptions) -> Vec<Result<Message>> {
+        api.client().history_stream(1, options).collect().await
+    }
+
+    fn ids(messages: Vec<Result<Message>>) -> Vec<i64> {
+        messages.into_iter().map(|m| m.unwrap().id()).collect()
+    }
+
+    #[tokio::test]
+    async fn backward() {
+        // starting messages of next pages are duplicated, the third page is short and the last one is empty
+        let api = MockedRawApi::new(vec![
+            page(&[10, 9, 8]),
+            page(&[8, 7, 6]),
+            page(&[6, 5]),
+            page(&[]),
+        ]);
+        let options = HistoryOptions::builder().with_page_size(3).build();
+        assert_eq!(ids(history(&api, options).await), vec![10, 9, 8, 7, 6, 5]);
+        assert_eq!(api.request_fields("from_message_id"), vec![0, 8, 6, 5]);
+    }
+
+    #[tokio::test]
+    async fn backward_from_message() {
+        // newer messages may be returned for the first page
+        let api = MockedRawApi::new(vec![page(&[9, 8, 7]), page(&[7])]);
+        let options = HistoryOptions::builder()
+            .with_from_message_id(8)
+            .with_page_size(3)
+            .build();
+        assert_eq!(ids(history(&api, options).await), vec![8, 7]);
+    }
+
+    #[tokio::test]
+    async fn forward() {
+        // the last page contains only already returned message
+        let api = MockedRawApi::new(vec![page(&[7, 6, 5]), page(&[9, 8, 7]), page(&[9])]);
+        let options = HistoryOptions::builder()
+            .with_from_message_id(5)
+            .with_direction(HistoryDirection::Forward)
+            .with_page_size(3)
+            .build();
+        assert_eq!(ids(history(&api, options).await), vec![5, 6, 7, 8, 9]);
+        assert_eq!(api.request_fields("from_message_id"), vec![5, 7, 9]);
+        assert_eq!(api.request_fields("offset"), vec![-2, -2, -2]);
+        assert_eq!(api.request_fields("limit"), vec![3, 3, 3]);
+
+        let api = MockedRawApi::new(vec![page(&[1])]);
+        let options = HistoryOptions::builder()
+            .with_direction(HistoryDirection::Forward)
+            .build();
+        let messages = history(&api, options).await;
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].is_err());
+        assert!(api.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn stop_date() {
+        let api = MockedRawApi::new(vec![page(&[30, 20, 10]), page(&[10])]);
+        let options = HistoryOptions::builder().with_stop_date(15).build();
+        assert_eq!(ids(history(&api, options).await), vec![30, 20]);
+        assert_eq!(api.requests().len(), 1);
+
+        let api = MockedRawApi::new(vec![page(&[30, 20, 10]), page(&[30])]);
+        let options = HistoryOptions::builder()
+            .with_from_message_id(10)
+            .with_direction(HistoryDirection::Forward)
+            .with_stop_date(25)
+            .build();
+        assert_eq!(ids(history(&api, options).await), vec![10, 20]);
+        assert_eq!(api.requests().len(), 1);
+    }
+}
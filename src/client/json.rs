@@ -0,0 +1,9 @@
+use serde_json::Value;
+
+// Returns integer field of TDLib object; 64-bit integers are sent by TDLib as strings.
+pub(crate) fn id(value: &Value, key: &str) -> Option<i64> {
+    let value = value.get(key)?;
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|v| v.parse().ok()))
+}
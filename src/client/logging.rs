@@ -0,0 +1,219 @@
+use super::tdlib_client::{TdJson, TdLibClient};
+use crate::errors::{Error, Result};
+use crate::tdjson;
+use crate::types::{
+    Error as TDLibError, LogStream, LogStreamEmpty, RFunction, SetLogStream,
+    SetLogTagVerbosityLevel, SetLogVerbosityLevel,
+};
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+const LOG_TARGET: &str = "tdlib";
+
+/// Configures TDLib internal logging with [SetLogVerbosityLevel](crate::types::SetLogVerbosityLevel),
+/// [SetLogTagVerbosityLevel](crate::types::SetLogTagVerbosityLevel) and [SetLogStream](crate::types::SetLogStream),
+/// and allows to forward TDLib log messages to the [log](https://docs.rs/log) crate with `tdlib` target.
+/// ```no_run
+/// use rust_tdlib::client::logging::TdlibLogging;
+/// TdlibLogging::builder()
+///     .with_verbosity_level(2)
+///     .with_tag_verbosity_level("td_requests", 1)
+///     // TDLib log is written only to the `log` crate
+///     .with_forwarding()
+///     .build()
+///     .init()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TdlibLogging {
+    verbosity_level: Option<i32>,
+    tags_verbosity_levels: HashMap<String, i32>,
+    stream: Option<LogStream>,
+    forward: bool,
+}
+
+impl TdlibLogging {
+    pub fn builder() -> TdlibLoggingBuilder {
+        TdlibLoggingBuilder::default()
+    }
+
+    /// Applies settings with [TdJson](crate::client::tdlib_client::TdJson).
+    pub fn init(&self) -> Result<()> {
+        self.init_with(&TdJson::new())
+    }
+
+    /// Applies settings with specified TDLib client.
+    pub fn init_with<C: TdLibClient>(&self, tdlib_client: &C) -> Result<()> {
+        // forwarded messages would be written to the default stream too
+        let stream = match (&self.stream, self.forward) {
+            (None, true) => Some(LogStream::Empty(LogStreamEmpty::builder().build())),
+            (stream, _) => stream.clone(),
+        };
+        if let Some(stream) = stream {
+            execute(
+                tdlib_client,
+                SetLogStream::builder().log_stream(stream).build(),
+            )?;
+        }
+        if let Some(verbosity_level) = self.verbosity_level {
+            execute(
+                tdlib_client,
+                SetLogVerbosityLevel::builder()
+                    .new_verbosity_level(verbosity_level)
+                    .build(),
+            )?;
+        }
+        for (tag, verbosity_level) in self.tags_verbosity_levels.iter() {
+            execute(
+                tdlib_client,
+                SetLogTagVerbosityLevel::builder()
+                    .tag(tag)
+                    .new_verbosity_level(*verbosity_level)
+                    .build(),
+            )?;
+        }
+        if self.forward {
+            forward_to_log(self.verbosity_level.unwrap_or(LOG_LEVEL_INFO));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct TdlibLoggingBuilder {
+    inner: TdlibLogging,
+}
+
+impl TdlibLoggingBuilder {
+    /// TDLib log verbosity level: 0 - fatal errors, 1 - errors, 2 - warnings, 3 - info, 4 - debug, 5 - verbose debug.
+    pub fn with_verbosity_level(mut self, verbosity_level: i32) -> Self {
+        self.inner.verbosity_level = Some(verbosity_level);
+        self
+    }
+
+    /// Verbosity level of particular TDLib log tag, such as `td_requests` or `net_query`.
+    pub fn with_tag_verbosity_level<T: AsRef<str>>(mut self, tag: T, verbosity_level: i32) -> Self {
+        self.inner
+            .tags_verbosity_levels
+            .insert(tag.as_ref().to_string(), verbosity_level);
+        self
+    }
+
+    /// Verbosity levels of TDLib log tags.
+    pub fn with_tags_verbosity_levels(mut self, tags: HashMap<String, i32>) -> Self {
+        self.inner.tags_verbosity_levels.extend(tags);
+        self
+    }
+
+    /// Where TDLib writes its log.
+    pub fn with_stream(mut self, stream: LogStream) -> Self {
+        self.inner.stream = Some(stream);
+        self
+    }
+
+    /// Forward TDLib log messages to the `log` crate, see [forward_to_log](crate::client::logging::forward_to_log).
+    /// Unless [with_stream](crate::client::logging::TdlibLoggingBuilder::with_stream) is specified, TDLib stops writing its log to stderr.
+    pub fn with_forwarding(mut self) -> Self {
+        self.inner.forward = true;
+        self
+    }
+
+    pub fn build(self) -> TdlibLogging {
+        self.inner
+    }
+}
+
+const LOG_LEVEL_FATAL: c_int = 0;
+const LOG_LEVEL_ERROR: c_int = 1;
+const LOG_LEVEL_WARNING: c_int = 2;
+const LOG_LEVEL_INFO: c_int = 3;
+const LOG_LEVEL_DEBUG: c_int = 4;
+
+/// Forwards TDLib log messages with verbosity level up to `max_verbosity_level` to the `log` crate with `tdlib` target.
+/// TDLib levels are mapped to `log` levels: fatal errors and errors to `Error`, warnings to `Warn`, info to `Info`, debug to `Debug` and more verbose levels to `Trace`.
+pub fn forward_to_log(max_verbosity_level: i32) {
+    tdjson::set_log_message_callback(max_verbosity_level, Some(log_message));
+}
+
+/// Stops forwarding TDLib log messages.
+pub fn stop_forwarding() {
+    tdjson::set_log_message_callback(0, None);
+}
+
+extern "C" fn log_message(verbosity_level: c_int, message: *const c_char) {
+    if message.is_null() {
+        return;
+    }
+    let message = unsafe { CStr::from_ptr(message) }.to_string_lossy();
+    log::log!(target: LOG_TARGET, log_level(verbosity_level), "{}", message.trim_end());
+}
+
+fn log_level(verbosity_level: c_int) -> log::Level {
+    match verbosity_level {
+        LOG_LEVEL_FATAL | LOG_LEVEL_ERROR => log::Level::Error,
+        LOG_LEVEL_WARNING => log::Level::Warn,
+        LOG_LEVEL_INFO => log::Level::Info,
+        LOG_LEVEL_DEBUG => log::Level::Debug,
+        _ => log::Level::Trace,
+    }
+}
+
+fn execute<C: TdLibClient, F: RFunction>(tdlib_client: &C, fnc: F) -> Result<()> {
+    let response = tdlib_client
+        .execute(fnc)?
+        .ok_or(Error::Internal("empty response for synchronous request"))?;
+    let value: serde_json::Value = serde_json::from_str(&response)?;
+    match value.get("@type").and_then(|t| t.as_str()) {
+        Some("error") => Err(Error::TDLibError(serde_json::from_value::<TDLibError>(
+            value,
+        )?)),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{log_level, TdlibLogging};
+    use crate::client::mocked_raw_api::MockedRawApi;
+    use crate::types::{LogStream, LogStreamFile};
+
+    #[test]
+    fn log_levels() {
+        assert_eq!(log_level(0), log::Level::Error);
+        assert_eq!(log_level(1), log::Level::Error);
+        assert_eq!(log_level(2), log::Level::Warn);
+        assert_eq!(log_level(3), log::Level::Info);
+        assert_eq!(log_level(4), log::Level::Debug);
+        assert_eq!(log_level(5), log::Level::Trace);
+        assert_eq!(log_level(1023), log::Level::Trace);
+    }
+
+    #[test]
+    fn forwarding_disables_default_stream() {
+        let api = MockedRawApi::default();
+        TdlibLogging::builder()
+            .with_verbosity_level(2)
+            .with_forwarding()
+            .build()
+            .init_with(&api)
+            .unwrap();
+        assert_eq!(
+            api.request_types(),
+            vec!["setLogStream", "setLogVerbosityLevel"]
+        );
+        assert_eq!(api.requests()[0]["log_stream"]["@type"], "logStreamEmpty");
+
+        // specified stream is kept
+        let api = MockedRawApi::default();
+        TdlibLogging::builder()
+            .with_stream(LogStream::File(
+                LogStreamFile::builder().path("td.log").build(),
+            ))
+            .with_forwarding()
+            .build()
+            .init_with(&api)
+            .unwrap();
+        assert_eq!(api.requests()[0]["log_stream"]["@type"], "logStreamFile");
+    }
+}
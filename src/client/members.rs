@@ -0,0 +1,388 @@
+use super::pagination::{paginate, Pager};
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::Result;
+use crate::types::{
+    ChatMember, GetSupergroupMembers, MessageSender, SupergroupMembersFilter,
+    SupergroupMembersFilterRecent, SupergroupMembersFilterSearch,
+};
+use futures::stream::Stream;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::watch;
+
+// TDLib returns at most 200 members per request
+const MAX_PAGE_SIZE: usize = 200;
+
+/// Queries which can be passed to [MembersOptionsBuilder::with_query_sweep](crate::client::members::MembersOptionsBuilder::with_query_sweep):
+/// Telegram returns limited number of members for a filter, but searching by every letter and digit finds most members of large groups.
+pub const DEFAULT_SWEEP_QUERIES: [&str; 36] = [
+    "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s",
+    "t", "u", "v", "w", "x", "y", "z", "0", "1", "2", "3", "4", "5", "6", "7", "8", "9",
+];
+
+/// Progress of [Client::supergroup_members_stream](crate::client::Client::supergroup_members_stream).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MembersProgress {
+    filter_index: usize,
+    filter_count: usize,
+    offset: usize,
+    total_count: i32,
+    unique_count: usize,
+    done: bool,
+}
+
+impl MembersProgress {
+    /// Index of the filter which is being enumerated.
+    pub fn filter_index(&self) -> usize {
+        self.filter_index
+    }
+
+    pub fn filter_count(&self) -> usize {
+        self.filter_count
+    }
+
+    /// Number of members received for current filter.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Number of members matching current filter, as reported by Telegram.
+    pub fn total_count(&self) -> i32 {
+        self.total_count
+    }
+
+    /// Number of unique members returned so far.
+    pub fn unique_count(&self) -> usize {
+        self.unique_count
+    }
+
+    /// `true` when all filters are enumerated.
+    pub fn done(&self) -> bool {
+        self.done
+    }
+}
+
+/// Settings of [Client::supergroup_members_stream](crate::client::Client::supergroup_members_stream).
+#[derive(Debug, Clone)]
+pub struct MembersOptions {
+    filters: Vec<SupergroupMembersFilter>,
+    page_size: usize,
+    progress: Option<Arc<watch::Sender<MembersProgress>>>,
+}
+
+impl Default for MembersOptions {
+    fn default() -> Self {
+        Self {
+            filters: vec![],
+            page_size: MAX_PAGE_SIZE,
+            progress: None,
+        }
+    }
+}
+
+impl MembersOptions {
+    pub fn builder() -> MembersOptionsBuilder {
+        MembersOptionsBuilder::default()
+    }
+
+    /// Filters enumerated one after another. If no filters specified, [Recent](crate::types::SupergroupMembersFilter::Recent) is used.
+    pub fn filters(&self) -> &[SupergroupMembersFilter] {
+        &self.filters
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct MembersOptionsBuilder {
+    inner: MembersOptions,
+}
+
+impl MembersOptionsBuilder {
+    /// Adds filter to enumerate; members found with several filters are returned once.
+    pub fn with_filter<T: AsRef<SupergroupMembersFilter>>(mut self, filter: T) -> Self {
+        self.inner.filters.push(filter.as_ref().clone());
+        self
+    }
+
+    /// Adds [Search](crate::types::SupergroupMembersFilter::Search) filter for every query, for example for [DEFAULT_SWEEP_QUERIES](crate::client::members::DEFAULT_SWEEP_QUERIES).
+    pub fn with_query_sweep<I, S>(mut self, queries: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.inner.filters.extend(queries.into_iter().map(|query| {
+            SupergroupMembersFilter::Search(
+                SupergroupMembersFilterSearch::builder()
+                    .query(query)
+                    .build(),
+            )
+        }));
+        self
+    }
+
+    /// Number of members requested at once. Default and maximum is 200.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.inner.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    /// Progress is sent after every page.
+    pub fn with_progress(mut self, progress: watch::Sender<MembersProgress>) -> Self {
+        self.inner.progress = Some(Arc::new(progress));
+        self
+    }
+
+    pub fn build(self) -> MembersOptions {
+        with_default_filter(self.inner)
+    }
+}
+
+// Options without filters enumerate recent members.
+fn with_default_filter(mut options: MembersOptions) -> MembersOptions {
+    if options.filters.is_empty() {
+        options.filters.push(SupergroupMembersFilter::Recent(
+            SupergroupMembersFilterRecent::builder().build(),
+        ));
+    }
+    options
+}
+
+// Users and chats have different identifier spaces.
+fn member_key(member: &ChatMember) -> (bool, i64) {
+    match member.member_id() {
+        MessageSender::Chat(chat) => (true, chat.chat_id()),
+        MessageSender::User(user) => (false, user.user_id()),
+        MessageSender::_Default => (false, 0),
+    }
+}
+
+struct MembersPager<'a, R>
+where
+    R: TdLibClient + Clone,
+{
+    client: &'a Client<R>,
+    supergroup_id: i64,
+    options: MembersOptions,
+    seen: HashSet<(bool, i64)>,
+    progress: MembersProgress,
+}
+
+impl<R> MembersPager<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    fn next_filter(&mut self) {
+        self.progress.filter_index += 1;
+        self.progress.offset = 0;
+        self.progress.total_count = 0;
+        self.progress.done = self.progress.filter_index >= self.options.filters.len();
+    }
+}
+
+impl<R> Pager for MembersPager<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    type Item = ChatMember;
+
+    fn is_done(&self) -> bool {
+        self.progress.done
+    }
+
+    async fn next_page(&mut self) -> Result<Vec<ChatMember>> {
+        let request = GetSupergroupMembers::builder()
+            .supergroup_id(self.supergroup_id)
+            .filter(&self.options.filters[self.progress.filter_index])
+            .offset(self.progress.offset as i32)
+            .limit(self.options.page_size as i32)
+            .build();
+        let members = self.client.get_supergroup_members(request).await?;
+        self.progress.offset += members.members().len();
+        self.progress.total_count = members.total_count();
+        if members.members().is_empty()
+            || self.progress.offset >= members.total_count().max(0) as usize
+        {
+            self.next_filter();
+        }
+        let page: Vec<ChatMember> = members
+            .members()
+            .iter()
+            .filter(|member| self.seen.insert(member_key(member)))
+            .cloned()
+            .collect();
+        self.progress.unique_count += page.len();
+        if let Some(progress) = &self.options.progress {
+            // error means that nobody waits for progress
+            let _ = progress.send(self.progress);
+        }
+        Ok(page)
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Returns stream of unique members of the supergroup or channel, enumerated with [GetSupergroupMembers](crate::types::GetSupergroupMembers)
+    /// page by page for every filter of the options. The stream ends when all filters are enumerated or after an error.
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use rust_tdlib::client::{members::{MembersOptions, DEFAULT_SWEEP_QUERIES}, Client, tdlib_client::TdJson};
+    /// # use rust_tdlib::types::{SupergroupMembersFilter, SupergroupMembersFilterRecent};
+    /// # async fn run(client: Client<TdJson>, supergroup_id: i64) -> rust_tdlib::errors::Result<()> {
+    /// let (progress, mut progress_receiver) = tokio::sync::watch::channel(Default::default());
+    /// let options = MembersOptions::builder()
+    ///     .with_filter(SupergroupMembersFilter::Recent(SupergroupMembersFilterRecent::builder().build()))
+    ///     .with_query_sweep(DEFAULT_SWEEP_QUERIES)
+    ///     .with_progress(progress)
+    ///     .build();
+    /// let mut members = Box::pin(client.supergroup_members_stream(supergroup_id, options));
+    /// while let Some(member) = members.next().await {
+    ///     println!("{:?}, {:?}", member?.member_id(), *progress_receiver.borrow_and_update());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn supergroup_members_stream(
+        &self,
+        supergroup_id: i64,
+        options: MembersOptions,
+    ) -> impl Stream<Item = Result<ChatMember>> + '_ {
+        let options = with_default_filter(options);
+        let progress = MembersProgress {
+            filter_count: options.filters.len(),
+            ..Default::default()
+        };
+        paginate(MembersPager {
+            client: self,
+            supergroup_id,
+            options,
+            seen: HashSet::new(),
+            progress,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{member_key, MembersOptions, DEFAULT_SWEEP_QUERIES};
+    use crate::client::mocked_raw_api::MockedRawApi;
+    use crate::types::SupergroupMembersFilter;
+    use futures::StreamExt;
+    use serde_json::{json, Value};
+
+    // Page of `total_count` members with user identifiers `ids`.
+    fn page(ids: &[i64], total_count: i32) -> Value {
+        let members: Vec<Value> = ids
+            .iter()
+            .map(|id| {
+                json!({
+                    "@type": "chatMember",
+                    "member_id": {"@type": "messageSenderUser", "user_id": id},
+                    "status": {"@type": "chatMemberStatusMember"}
+                })
+            })
+            .collect();
+        json!({"@type": "chatMembers", "total_count": total_count, "members": members})
+    }
+
+    async fn members(api: &MockedRawApi, options: MembersOptions) -> Vec<i64> {
+        let client = api.client();
+        client
+            .supergroup_members_stream(1, options)
+            .map(|member| member_key(&member.unwrap()).1)
+            .collect()
+            .await
+    }
+
+    // Filter types and offsets of sent requests.
+    fn requests(api: &MockedRawApi) -> Vec<(String, i64)> {
+        api.requests()
+            .iter()
+            .map(|request| {
+                (
+                    request["filter"]["@type"].as_str().unwrap().to_string(),
+                    request["offset"].as_i64().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn build_filters() {
+        let options = MembersOptions::builder().build();
+        assert!(matches!(
+            options.filters(),
+            [SupergroupMembersFilter::Recent(_)]
+        ));
+
+        let options = MembersOptions::builder()
+            .with_query_sweep(DEFAULT_SWEEP_QUERIES)
+            .with_page_size(1000)
+            .build();
+        assert_eq!(options.filters().len(), 36);
+        assert_eq!(options.page_size(), 200);
+        match &options.filters()[1] {
+            SupergroupMembersFilter::Search(search) => assert_eq!(search.query(), "b"),
+            _ => panic!("search filter expected"),
+        }
+    }
+
+    #[tokio::test]
+    async fn default_options() {
+        let api = MockedRawApi::new(vec![page(&[1, 2], 2)]);
+        assert_eq!(members(&api, MembersOptions::default()).await, vec![1, 2]);
+        assert_eq!(
+            requests(&api),
+            vec![("supergroupMembersFilterRecent".to_string(), 0)]
+        );
+    }
+
+    #[tokio::test]
+    async fn unique_members() {
+        let api = MockedRawApi::new(vec![
+            page(&[1, 2], 3),
+            page(&[3], 3),
+            page(&[2, 4], 2),
+            page(&[1, 5], 2),
+        ]);
+        let options = MembersOptions::builder()
+            .with_page_size(2)
+            .with_query_sweep(["a", "b", "c"])
+            .build();
+        assert_eq!(members(&api, options).await, vec![1, 2, 3, 4, 5]);
+        assert_eq!(api.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn next_filter() {
+        // the first filter returns less members than reported, the second one returns nothing
+        let api = MockedRawApi::new(vec![
+            page(&[1, 2], 10),
+            page(&[3], 10),
+            page(&[], 10),
+            page(&[], 5),
+            page(&[4], 1),
+        ]);
+        let options = MembersOptions::builder()
+            .with_page_size(2)
+            .with_query_sweep(["a", "b", "c"])
+            .build();
+        assert_eq!(members(&api, options).await, vec![1, 2, 3, 4]);
+        let search = "supergroupMembersFilterSearch".to_string();
+        assert_eq!(
+            requests(&api),
+            vec![
+                (search.clone(), 0),
+                (search.clone(), 2),
+                (search.clone(), 3),
+                (search.clone(), 0),
+                (search, 0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,421 @@
+use super::json::id;
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{GetChatHistory, Message};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+// TDLib returns at most 100 messages per request
+const HISTORY_LIMIT: usize = 100;
+// lower bound of the range which reaches the beginning of the chat history
+const HISTORY_START: i64 = 0;
+const MESSAGE_CACHE_NOT_SPECIFIED: Error = Error::BadRequest(
+    "message cache not specified, need to call `ClientBuilder::with_message_cache(...)`",
+);
+
+// Fields of the message which TDLib sends in `updateMessage*` updates; update field name to message field name.
+const MESSAGE_FIELDS: [(&str, &str); 4] = [
+    ("new_content", "content"),
+    ("edit_date", "edit_date"),
+    ("reply_markup", "reply_markup"),
+    ("interaction_info", "interaction_info"),
+];
+
+#[derive(Debug, Default)]
+struct ChatHistory {
+    messages: BTreeMap<i64, Message>,
+    // sorted disjoint inclusive ranges of message identifiers, within which all messages are cached
+    ranges: Vec<(i64, i64)>,
+    last_message_id: Option<i64>,
+}
+
+impl ChatHistory {
+    fn add_range(&mut self, lo: i64, hi: i64) {
+        if lo > hi {
+            return;
+        }
+        let (mut lo, mut hi) = (lo, hi);
+        self.ranges.retain(|&(l, h)| {
+            let touches = l <= hi.saturating_add(1) && lo <= h.saturating_add(1);
+            if touches {
+                lo = lo.min(l);
+                hi = hi.max(h);
+            }
+            !touches
+        });
+        let position = self.ranges.partition_point(|&(l, _)| l < lo);
+        self.ranges.insert(position, (lo, hi));
+    }
+
+    fn range(&self, message_id: i64) -> Option<(i64, i64)> {
+        self.ranges
+            .iter()
+            .find(|(lo, hi)| *lo <= message_id && message_id <= *hi)
+            .copied()
+    }
+
+    // returns identifier of the newest message which must be returned for `from_message_id`
+    fn top(&self, from_message_id: i64) -> Option<i64> {
+        match from_message_id {
+            0 => self.last_message_id,
+            from_message_id => Some(from_message_id - 1),
+        }
+    }
+
+    fn history(&self, from_message_id: i64, limit: usize) -> Vec<Message> {
+        let top = match self.top(from_message_id) {
+            None => return vec![],
+            Some(top) => top,
+        };
+        match self.range(top) {
+            None => vec![],
+            Some((lo, _)) => self
+                .messages
+                .range(lo..=top)
+                .rev()
+                .take(limit)
+                .map(|(_, message)| message.clone())
+                .collect(),
+        }
+    }
+
+    fn reaches_start(&self, from_message_id: i64) -> bool {
+        self.top(from_message_id)
+            .and_then(|top| self.range(top))
+            .is_some_and(|(lo, _)| lo == HISTORY_START)
+    }
+
+    fn add_history(&mut self, from_message_id: i64, messages: &[Message]) {
+        let (newest, oldest) = match (messages.first(), messages.last()) {
+            (Some(newest), Some(oldest)) => (newest.id(), oldest.id()),
+            // empty result means that there are no older messages
+            _ => {
+                self.add_range(HISTORY_START, from_message_id - 1);
+                return;
+            }
+        };
+        if from_message_id == 0 {
+            self.last_message_id = Some(self.last_message_id.unwrap_or_default().max(newest));
+            self.add_range(oldest, newest);
+        } else {
+            self.add_range(oldest, from_message_id - 1);
+        }
+        for message in messages {
+            self.messages.insert(message.id(), message.clone());
+        }
+    }
+
+    fn add_new(&mut self, message: Message) {
+        let message_id = message.id();
+        match self.last_message_id {
+            Some(last) if last >= message_id => {}
+            Some(last) => {
+                // new messages come in order, so the message continues the range of the last message
+                if self.range(last).is_some() {
+                    self.add_range(last, message_id);
+                }
+                self.last_message_id = Some(message_id);
+            }
+            None => {
+                self.add_range(message_id, message_id);
+                self.last_message_id = Some(message_id);
+            }
+        }
+        self.messages.insert(message_id, message);
+    }
+
+    fn replace(&mut self, old_message_id: i64, message: Message) {
+        let message_id = message.id();
+        if self.messages.remove(&old_message_id).is_none() {
+            return;
+        }
+        if self.last_message_id == Some(old_message_id) {
+            self.add_range(old_message_id, message_id);
+            self.last_message_id = Some(message_id.max(old_message_id));
+        }
+        self.messages.insert(message_id, message);
+    }
+}
+
+/// Per-chat cache of messages, filled by [Client::get_chat_history_cached](crate::client::Client::get_chat_history_cached)
+/// and [UpdateNewMessage](crate::types::UpdateNewMessage), and kept up-to-date with updates of particular client, such as
+/// [UpdateMessageContent](crate::types::UpdateMessageContent) or [UpdateDeleteMessages](crate::types::UpdateDeleteMessages).
+/// The cache remembers which ranges of the history are loaded without gaps, so only missing messages are requested from TDLib.
+/// Use [ClientBuilder::with_message_cache](crate::client::ClientBuilder::with_message_cache) to enable it.
+#[derive(Debug, Clone, Default)]
+pub struct MessageCache {
+    chats: Arc<RwLock<HashMap<i64, ChatHistory>>>,
+}
+
+fn message(update: &Value) -> Option<Message> {
+    match serde_json::from_value(update.get("message")?.clone()) {
+        Ok(message) => Some(message),
+        Err(err) => {
+            log::error!("message cache can't deserialize message: {}", err);
+            None
+        }
+    }
+}
+
+// Message has no setters, so changed fields are merged into its json representation.
+fn merge_message(message: &Message, update: &Value) -> Option<Message> {
+    let mut value = serde_json::to_value(message).ok()?;
+    let fields = value.as_object_mut()?;
+    for (update_field, field) in MESSAGE_FIELDS {
+        if let Some(field_value) = update.get(update_field) {
+            fields.insert(field.to_string(), field_value.clone());
+        }
+    }
+    match serde_json::from_value(value) {
+        Ok(message) => Some(message),
+        Err(err) => {
+            log::error!("message cache can't apply message update: {}", err);
+            None
+        }
+    }
+}
+
+impl MessageCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn message(&self, chat_id: i64, message_id: i64) -> Option<Message> {
+        self.chats
+            .read()
+            .unwrap()
+            .get(&chat_id)?
+            .messages
+            .get(&message_id)
+            .cloned()
+    }
+
+    /// Returns up to `limit` cached messages older than `from_message_id`, newest first, stopping at the first gap.
+    /// Use 0 as `from_message_id` to start from the last message of the chat.
+    pub fn history(&self, chat_id: i64, from_message_id: i64, limit: usize) -> Vec<Message> {
+        self.chats
+            .read()
+            .unwrap()
+            .get(&chat_id)
+            .map(|chat| chat.history(from_message_id, limit))
+            .unwrap_or_default()
+    }
+
+    /// Returns ranges of message identifiers, inclusive, which are cached without gaps. Range starting with 0 reaches the beginning of the chat.
+    pub fn ranges(&self, chat_id: i64) -> Vec<(i64, i64)> {
+        self.chats
+            .read()
+            .unwrap()
+            .get(&chat_id)
+            .map(|chat| chat.ranges.clone())
+            .unwrap_or_default()
+    }
+
+    /// Removes cached messages of the chat.
+    pub fn clear_chat(&self, chat_id: i64) {
+        self.chats.write().unwrap().remove(&chat_id);
+    }
+
+    pub fn clear(&self) {
+        self.chats.write().unwrap().clear();
+    }
+
+    /// Adds result of [GetChatHistory](crate::types::GetChatHistory) with zero offset.
+    pub(crate) fn add_history(&self, chat_id: i64, from_message_id: i64, messages: &[Message]) {
+        self.chats
+            .write()
+            .unwrap()
+            .entry(chat_id)
+            .or_default()
+            .add_history(from_message_id, messages)
+    }
+
+    fn reaches_start(&self, chat_id: i64, from_message_id: i64) -> bool {
+        self.chats
+            .read()
+            .unwrap()
+            .get(&chat_id)
+            .is_some_and(|chat| chat.reaches_start(from_message_id))
+    }
+
+    /// Applies raw TDLib update.
+    pub(crate) fn apply(&self, update: &Value) {
+        let type_ = match update.get("@type").and_then(|t| t.as_str()) {
+            None => return,
+            Some(type_) => type_,
+        };
+        let mut chats = self.chats.write().unwrap();
+        match type_ {
+            "updateNewMessage" => {
+                if let Some(message) = message(update) {
+                    chats.entry(message.chat_id()).or_default().add_new(message);
+                }
+            }
+            "updateMessageSendSucceeded" => {
+                if let (Some(message), Some(old_message_id)) =
+                    (message(update), id(update, "old_message_id"))
+                {
+                    if let Some(chat) = chats.get_mut(&message.chat_id()) {
+                        chat.replace(old_message_id, message);
+                    }
+                }
+            }
+            "updateMessageContent" | "updateMessageEdited" | "updateMessageInteractionInfo" => {
+                let (chat_id, message_id) = match (id(update, "chat_id"), id(update, "message_id"))
+                {
+                    (Some(chat_id), Some(message_id)) => (chat_id, message_id),
+                    _ => return,
+                };
+                let message = chats
+                    .get_mut(&chat_id)
+                    .and_then(|chat| chat.messages.get_mut(&message_id));
+                if let Some(message) = message {
+                    if let Some(merged) = merge_message(message, update) {
+                        *message = merged;
+                    }
+                }
+            }
+            // messages deleted only from TDLib cache still exist, so they are kept
+            "updateDeleteMessages" if update.get("is_permanent") == Some(&Value::Bool(true)) => {
+                let chat = match id(update, "chat_id").and_then(|chat_id| chats.get_mut(&chat_id)) {
+                    None => return,
+                    Some(chat) => chat,
+                };
+                let message_ids = update.get("message_ids").and_then(|ids| ids.as_array());
+                for message_id in message_ids.into_iter().flatten() {
+                    if let Some(message_id) = message_id.as_i64() {
+                        chat.messages.remove(&message_id);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Returns up to `limit` messages older than `from_message_id`, newest first, like [GetChatHistory](crate::types::GetChatHistory) with zero offset does.
+    /// Cached messages are returned as is; only missing parts of the history are requested from TDLib.
+    /// Fewer messages are returned only if the beginning of the chat is reached.
+    /// Requires [ClientBuilder::with_message_cache](crate::client::ClientBuilder::with_message_cache).
+    pub async fn get_chat_history_cached(
+        &self,
+        chat_id: i64,
+        from_message_id: i64,
+        limit: usize,
+    ) -> Result<Vec<Message>> {
+        let cache = self.message_cache().ok_or(MESSAGE_CACHE_NOT_SPECIFIED)?;
+        let mut result = cache.history(chat_id, from_message_id, limit);
+        while result.len() < limit {
+            let from_message_id = result.last().map_or(from_message_id, |m| m.id());
+            if cache.reaches_start(chat_id, from_message_id) {
+                break;
+            }
+            let request = GetChatHistory::builder()
+                .chat_id(chat_id)
+                .from_message_id(from_message_id)
+                .limit((limit - result.len()).min(HISTORY_LIMIT) as i32)
+                .build();
+            let messages = self.get_chat_history(request).await?;
+            let messages: Vec<Message> = messages.messages().iter().flatten().cloned().collect();
+            cache.add_history(chat_id, from_message_id, &messages);
+            let loaded = cache.history(chat_id, from_message_id, limit - result.len());
+            if loaded.is_empty() {
+                break;
+            }
+            result.extend(loaded);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChatHistory, MessageCache};
+    use crate::client::mocked_raw_api::{self, MockedRawApi};
+    use crate::client::Client;
+    use crate::types::Message;
+    use serde_json::json;
+
+    fn message(id: i64) -> serde_json::Value {
+        json!({"@type": "message", "id": id, "chat_id": 1, "sender_id": {"@type": "messageSenderChat", "chat_id": 1}, "content": {"@type": "messageText", "text": {"@type": "formattedText", "text": id.to_string()}}})
+    }
+
+    fn messages(ids: &[i64]) -> Vec<Message> {
+        ids.iter()
+            .map(|id| serde_json::from_value(message(*id)).unwrap())
+            .collect()
+    }
+
+    fn ids(messages: Vec<Message>) -> Vec<i64> {
+        messages.iter().map(|m| m.id()).collect()
+    }
+
+    #[test]
+    fn merge_ranges() {
+        let mut chat = ChatHistory::default();
+        chat.add_range(10, 20);
+        chat.add_range(30, 40);
+        chat.add_range(50, 60);
+        assert_eq!(chat.ranges, vec![(10, 20), (30, 40), (50, 60)]);
+        chat.add_range(21, 29);
+        assert_eq!(chat.ranges, vec![(10, 40), (50, 60)]);
+        chat.add_range(35, 55);
+        assert_eq!(chat.ranges, vec![(10, 60)]);
+        chat.add_range(1, 2);
+        assert_eq!(chat.ranges, vec![(1, 2), (10, 60)]);
+    }
+
+    #[test]
+    fn track_gaps() {
+        let cache = MessageCache::new();
+        cache.add_history(1, 0, &messages(&[50, 40, 30]));
+        cache.add_history(1, 10, &messages(&[8, 5]));
+        assert_eq!(cache.ranges(1), vec![(5, 9), (30, 50)]);
+        assert_eq!(ids(cache.history(1, 0, 10)), vec![50, 40, 30]);
+        assert!(cache.history(1, 30, 10).is_empty());
+
+        cache.add_history(1, 30, &messages(&[20, 10]));
+        assert_eq!(ids(cache.history(1, 0, 10)), vec![50, 40, 30, 20, 10, 8, 5]);
+        cache.add_history(1, 5, &[]);
+        assert_eq!(cache.ranges(1), vec![(0, 50)]);
+        assert!(cache.reaches_start(1, 20));
+
+        cache.apply(&json!({"@type": "updateNewMessage", "message": message(60)}));
+        cache.apply(&json!({"@type": "updateMessageSendSucceeded", "message": message(70), "old_message_id": 60}));
+        cache.apply(&json!({"@type": "updateDeleteMessages", "chat_id": 1, "message_ids": [40], "is_permanent": true, "from_cache": false}));
+        cache.apply(&json!({"@type": "updateMessageEdited", "chat_id": 1, "message_id": 50, "edit_date": 123}));
+        assert_eq!(cache.ranges(1), vec![(0, 70)]);
+        assert_eq!(ids(cache.history(1, 0, 3)), vec![70, 50, 30]);
+        assert_eq!(cache.message(1, 50).unwrap().edit_date(), 123);
+    }
+
+    #[tokio::test]
+    async fn cached_history_gap() {
+        let cache = MessageCache::new();
+        cache.add_history(1, 0, &messages(&[50, 40, 30]));
+        cache.add_history(1, 10, &messages(&[8, 5]));
+        let page: Vec<_> = [20, 10, 8]
+            .iter()
+            .map(|id| mocked_raw_api::message(1, *id, 0))
+            .collect();
+        let api = MockedRawApi::new(vec![mocked_raw_api::messages(page)]);
+        let client = api.client_with(Client::builder().with_message_cache(cache));
+
+        let history = client.get_chat_history_cached(1, 0, 7).await.unwrap();
+        assert_eq!(ids(history), vec![50, 40, 30, 20, 10, 8, 5]);
+        assert_eq!(api.request_types(), vec!["getChatHistory"]);
+        assert_eq!(api.request_fields("from_message_id"), vec![30]);
+        assert_eq!(api.request_fields("limit"), vec![4]);
+
+        // everything is cached now
+        let history = client.get_chat_history_cached(1, 45, 3).await.unwrap();
+        assert_eq!(ids(history), vec![40, 30, 20]);
+        assert_eq!(api.requests().len(), 1);
+    }
+}
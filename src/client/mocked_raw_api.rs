@@ -0,0 +1,123 @@
+use super::executor::default_executor;
+use super::observer::OBSERVER;
+use super::tdlib_client::{TdJson, TdLibClient};
+use super::{Client, ClientBuilder};
+use crate::errors::Result;
+use crate::tdjson;
+use crate::types::{RFunction, TdlibParameters};
+use serde_json::{json, Value};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// TDLib client, which responds to every request with the next prepared response; synchronous requests always succeed.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct MockedRawApi {
+    responses: Arc<Mutex<VecDeque<Value>>>,
+    requests: Arc<Mutex<Vec<Value>>>,
+}
+
+impl MockedRawApi {
+    pub fn new(responses: Vec<Value>) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(responses.into())),
+            requests: Arc::default(),
+        }
+    }
+
+    // Returns client with assigned client id, which sends requests to the mock.
+    pub fn client(&self) -> Client<Self> {
+        self.client_with(Client::builder())
+    }
+
+    // Returns client with components of the builder, bound with default executor like by a worker.
+    pub fn client_with(&self, builder: ClientBuilder<TdJson>) -> Client<Self> {
+        let mut client = builder
+            .with_tdlib_parameters(TdlibParameters::default())
+            .with_tdlib_client(self.clone())
+            .build()
+            .unwrap();
+        client.set_client_id(self.new_client()).unwrap();
+        if let Some(executor) = default_executor() {
+            client.set_executor(executor);
+        }
+        client
+    }
+
+    // Returns all sent requests.
+    pub fn requests(&self) -> Vec<Value> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    // Returns types of all sent requests.
+    pub fn request_types(&self) -> Vec<String> {
+        self.requests()
+            .iter()
+            .map(|request| request["@type"].as_str().unwrap_or_default().to_string())
+            .collect()
+    }
+
+    // Returns `key` field of all sent requests.
+    pub fn request_fields(&self, key: &str) -> Vec<Value> {
+        self.requests()
+            .iter()
+            .map(|request| request[key].clone())
+            .collect()
+    }
+}
+
+// Response to request with `code` error.
+pub(crate) fn error(code: i32) -> Value {
+    json!({"@type": "error", "code": code, "message": "error"})
+}
+
+pub(crate) fn ok() -> Value {
+    json!({"@type": "ok"})
+}
+
+pub(crate) fn message(chat_id: i64, id: i64, date: i32) -> Value {
+    json!({
+        "@type": "message",
+        "id": id,
+        "chat_id": chat_id,
+        "date": date,
+        "sender_id": {"@type": "messageSenderChat", "chat_id": chat_id},
+        "content": {"@type": "messageText", "text": {"@type": "formattedText", "text": ""}}
+    })
+}
+
+// Response to history or search request.
+pub(crate) fn messages(messages: Vec<Value>) -> Value {
+    json!({"@type": "messages", "total_count": messages.len(), "messages": messages})
+}
+
+impl TdLibClient for MockedRawApi {
+    fn send<Fnc: RFunction>(&self, _client_id: tdjson::ClientId, fnc: Fnc) -> Result<()> {
+        let request: Value = serde_json::from_str(&fnc.to_json()?)?;
+        let mut response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_else(|| error(500));
+        response["@extra"] = request["@extra"].clone();
+        self.requests.lock().unwrap().push(request);
+        OBSERVER.notify(response);
+        Ok(())
+    }
+
+    fn receive(&self, _timeout: f64) -> Option<String> {
+        None
+    }
+
+    fn execute<Fnc: RFunction>(&self, fnc: Fnc) -> Result<Option<String>> {
+        self.requests
+            .lock()
+            .unwrap()
+            .push(serde_json::from_str(&fnc.to_json()?)?);
+        Ok(Some(ok().to_string()))
+    }
+
+    fn new_client(&self) -> tdjson::ClientId {
+        1
+    }
+}
@@ -13,24 +13,131 @@ pub mod auth_handler;
 #[doc(hidden)]
 pub mod tdlib_client;
 
-pub use auth_handler::{AuthStateHandler, ConsoleAuthStateHandler, SignalAuthStateHandler};
+#[cfg(test)]
+mod mocked_raw_api;
+
+/// Observable client state.
+pub mod state;
+
+/// Connection state tracking.
+pub mod connection;
+
+/// Proxies management.
+pub mod proxy;
+
+/// Proxy urls and links parsing.
+pub mod proxy_url;
+
+/// Client and worker configuration loading.
+pub mod config;
+
+/// TDLib internal log configuration.
+pub mod logging;
+
+pub mod executor;
+
+/// Bounded-concurrency batch requests.
+pub mod batch;
+
+/// Merging and caching of read-only requests.
+pub mod cache;
+
+/// Chats, users and groups built from updates.
+pub mod store;
+
+/// Sorted chat lists built from updates.
+pub mod chat_list;
+
+pub mod snapshot;
+
+/// Per-chat message history cache.
+pub mod message_cache;
+
+pub mod options;
+
+mod pagination;
+
+mod json;
+
+mod seconds;
+
+/// Chat history pagination.
+pub mod history;
+
+/// Pagination of global and in-chat message search.
+pub mod search;
+
+/// Enumeration of supergroup members.
+pub mod members;
+
+pub mod event_log;
+
+/// Progress of file transfers, shared by downloads and uploads.
+pub mod transfer;
+
+pub mod download;
+
+pub mod upload;
+
+pub mod generation;
+
+/// Async reading of files while they download.
+pub mod file_reader;
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
+
+pub use auth_handler::{
+    AuthStateHandler, ConsoleAuthStateHandler, SignalAuthStateHandler, WaitPasswordAction,
+};
+pub use batch::{flood_wait_duration, BatchOptions, BatchOptionsBuilder};
+pub use cache::{RequestCache, RequestCacheBuilder};
+pub use chat_list::{ChatListEntry, ChatListKind, ChatLists};
+pub use connection::{ConnectionStateKind, ConnectionStats, ConnectionTransition};
+pub use download::{
+    Download, DownloadManager, DownloadManagerBuilder, DownloadProgress, DownloadStatus,
+    PendingDownload,
+};
+pub use event_log::{AuditRecord, EventLogOptions, EventLogOptionsBuilder};
+use executor::Executor;
+pub use file_reader::{FileReader, FileReaderOptions, FileReaderOptionsBuilder};
+pub use generation::{FileGeneration, FileGenerator, FileGenerators};
+pub use history::{HistoryDirection, HistoryOptions, HistoryOptionsBuilder};
 use log::debug;
+pub use logging::{TdlibLogging, TdlibLoggingBuilder};
+pub use members::{MembersOptions, MembersOptionsBuilder, MembersProgress};
+pub use message_cache::MessageCache;
 use observer::OBSERVER;
+pub use options::Options;
+pub use proxy::{ProxyManager, ProxyManagerBuilder, ProxyStats};
+pub use proxy_url::{parse_proxy_url, proxy_link};
+pub use search::{
+    ChatSearchOptions, ChatSearchOptionsBuilder, SearchOptions, SearchOptionsBuilder,
+};
 use serde::de::DeserializeOwned;
+use serde_json::Value;
+pub use snapshot::Snapshot;
+pub use state::ClientStatus;
+use state::StatusSender;
+use std::sync::Arc;
+pub use store::{Store, StoreChange};
+pub use transfer::{TransferHandle, TransferProgress};
+pub use upload::{Upload, UploadProgress, UploadStatus, UploadTracker, UploadedFile};
 pub use worker::{Worker, WorkerBuilder};
 
-use crate::types::{Close, Ok, RFunction, TdlibParameters, Update};
+use crate::types::{Close, ConnectionState, Ok, RFunction, TdlibParameters, Update};
 use crate::{
     errors::{Error, Result},
     types::Error as TDLibError,
 };
 use tdlib_client::{TdJson, TdLibClient};
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc, watch};
 
 const CLIENT_NOT_AUTHORIZED: Error = Error::Internal("client not authorized yet");
 const CLOSED_RECEIVER_ERROR: Error = Error::Internal("receiver already closed");
 const INVALID_RESPONSE_ERROR: Error = Error::Internal("receive invalid response");
 const NO_EXTRA: Error = Error::Internal("invalid tdlib response type, not have `extra` field");
+const CLIENT_CLOSED: Error = Error::Internal("client closed");
 
 /// Represents state of particular client instance.
 #[derive(Debug, Clone, PartialEq)]
@@ -56,6 +163,17 @@ where
     updates_sender: Option<mpsc::Sender<Box<Update>>>,
     tdlib_parameters: TdlibParameters,
     auth_state_channel_size: Option<usize>,
+    status: Arc<StatusSender>,
+    proxy_manager: Option<ProxyManager>,
+    executor: Option<Arc<dyn Executor>>,
+    request_cache: Option<RequestCache>,
+    store: Option<Store>,
+    chat_lists: Option<ChatLists>,
+    message_cache: Option<MessageCache>,
+    download_manager: Option<DownloadManager>,
+    upload_tracker: Option<UploadTracker>,
+    file_generators: Option<FileGenerators>,
+    options: Options,
 }
 
 impl<S> Client<S>
@@ -102,6 +220,105 @@ where
     pub(crate) fn updates_sender(&self) -> &Option<mpsc::Sender<Box<Update>>> {
         &self.updates_sender
     }
+
+    /// Returns [ProxyManager](crate::client::proxy::ProxyManager) specified with [ClientBuilder::with_proxy_manager](crate::client::ClientBuilder::with_proxy_manager).
+    pub fn proxy_manager(&self) -> Option<&ProxyManager> {
+        self.proxy_manager.as_ref()
+    }
+
+    // Executor of the worker the client bound with.
+    pub(crate) fn executor(&self) -> Option<&dyn Executor> {
+        self.executor.as_deref()
+    }
+
+    pub(crate) fn set_executor(&mut self, executor: Arc<dyn Executor>) {
+        self.executor = Some(executor);
+    }
+
+    /// Returns [RequestCache](crate::client::cache::RequestCache) specified with [ClientBuilder::with_request_cache](crate::client::ClientBuilder::with_request_cache).
+    pub fn request_cache(&self) -> Option<&RequestCache> {
+        self.request_cache.as_ref()
+    }
+
+    /// Returns [Store](crate::client::store::Store) specified with [ClientBuilder::with_store](crate::client::ClientBuilder::with_store).
+    pub fn store(&self) -> Option<&Store> {
+        self.store.as_ref()
+    }
+
+    /// Returns [ChatLists](crate::client::chat_list::ChatLists) specified with [ClientBuilder::with_chat_lists](crate::client::ClientBuilder::with_chat_lists).
+    pub fn chat_lists(&self) -> Option<&ChatLists> {
+        self.chat_lists.as_ref()
+    }
+
+    /// Returns [MessageCache](crate::client::message_cache::MessageCache) specified with [ClientBuilder::with_message_cache](crate::client::ClientBuilder::with_message_cache).
+    pub fn message_cache(&self) -> Option<&MessageCache> {
+        self.message_cache.as_ref()
+    }
+
+    /// Returns [DownloadManager](crate::client::download::DownloadManager) specified with [ClientBuilder::with_download_manager](crate::client::ClientBuilder::with_download_manager).
+    pub fn download_manager(&self) -> Option<&DownloadManager> {
+        self.download_manager.as_ref()
+    }
+
+    /// Returns [UploadTracker](crate::client::upload::UploadTracker) specified with [ClientBuilder::with_upload_tracker](crate::client::ClientBuilder::with_upload_tracker).
+    pub fn upload_tracker(&self) -> Option<&UploadTracker> {
+        self.upload_tracker.as_ref()
+    }
+
+    /// Returns [FileGenerators](crate::client::generation::FileGenerators) specified with [ClientBuilder::with_file_generators](crate::client::ClientBuilder::with_file_generators).
+    pub fn file_generators(&self) -> Option<&FileGenerators> {
+        self.file_generators.as_ref()
+    }
+
+    /// Returns [Options](crate::client::options::Options) received from TDLib.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
+
+    pub(crate) fn status_sender(&self) -> &StatusSender {
+        &self.status
+    }
+
+    /// Returns current state of the client.
+    pub fn status(&self) -> ClientStatus {
+        self.status.current()
+    }
+
+    /// Returns [Receiver](tokio::sync::watch::Receiver) which allows you to observe client state changes.
+    /// Unlike [Worker::wait_client_state](crate::client::worker::Worker::wait_client_state), any number of receivers can be used simultaneously.
+    pub fn state_watcher(&self) -> watch::Receiver<ClientStatus> {
+        self.status.subscribe()
+    }
+
+    /// Returns the latest [ConnectionState](crate::types::ConnectionState) received from TDLib.
+    pub fn connection_state(&self) -> Option<ConnectionState> {
+        self.status.current().connection_state().cloned()
+    }
+
+    /// Returns time spent by the client in each connection state.
+    pub fn connection_stats(&self) -> ConnectionStats {
+        self.status.connection_stats()
+    }
+
+    /// Returns [Receiver](tokio::sync::broadcast::Receiver) of connection state changes.
+    /// Only changes happened after the call will be received.
+    pub fn connection_transitions(&self) -> broadcast::Receiver<ConnectionTransition> {
+        self.status.subscribe_connection_transitions()
+    }
+
+    /// Waits until the client is authorized.
+    /// Returns error if the client closed before authorization.
+    pub async fn authorized(&self) -> Result<()> {
+        let mut watcher = self.state_watcher();
+        loop {
+            match watcher.borrow_and_update().client_state() {
+                Some(ClientState::Opened) => return Ok(()),
+                Some(ClientState::Closed) => return Err(CLIENT_CLOSED),
+                _ => {}
+            };
+            watcher.changed().await.map_err(|_| CLIENT_CLOSED)?;
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -113,6 +330,14 @@ where
     tdlib_parameters: Option<TdlibParameters>,
     tdlib_client: R,
     auth_state_channel_size: Option<usize>,
+    proxy_manager: Option<ProxyManager>,
+    request_cache: Option<RequestCache>,
+    store: Option<Store>,
+    chat_lists: Option<ChatLists>,
+    message_cache: Option<MessageCache>,
+    download_manager: Option<DownloadManager>,
+    upload_tracker: Option<UploadTracker>,
+    file_generators: Option<FileGenerators>,
 }
 
 impl Default for ClientBuilder<TdJson> {
@@ -121,6 +346,14 @@ impl Default for ClientBuilder<TdJson> {
             updates_sender: None,
             tdlib_parameters: None,
             auth_state_channel_size: None,
+            proxy_manager: None,
+            request_cache: None,
+            store: None,
+            chat_lists: None,
+            message_cache: None,
+            download_manager: None,
+            upload_tracker: None,
+            file_generators: None,
             tdlib_client: TdJson::new(),
         }
     }
@@ -151,6 +384,54 @@ where
         self
     }
 
+    /// [ProxyManager](crate::client::proxy::ProxyManager) will be started when the client is bound with [Worker](crate::client::worker::Worker).
+    pub fn with_proxy_manager(mut self, proxy_manager: ProxyManager) -> Self {
+        self.proxy_manager = Some(proxy_manager);
+        self
+    }
+
+    /// Enables merging and caching of read-only requests, see [RequestCache](crate::client::cache::RequestCache).
+    pub fn with_request_cache(mut self, request_cache: RequestCache) -> Self {
+        self.request_cache = Some(request_cache);
+        self
+    }
+
+    /// Keeps chats, users and groups received with updates, see [Store](crate::client::store::Store).
+    pub fn with_store(mut self, store: Store) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Keeps sorted chat lists, see [ChatLists](crate::client::chat_list::ChatLists).
+    pub fn with_chat_lists(mut self, chat_lists: ChatLists) -> Self {
+        self.chat_lists = Some(chat_lists);
+        self
+    }
+
+    /// Keeps loaded messages of chats, see [MessageCache](crate::client::message_cache::MessageCache).
+    pub fn with_message_cache(mut self, message_cache: MessageCache) -> Self {
+        self.message_cache = Some(message_cache);
+        self
+    }
+
+    /// Tracks downloads started with [Client::download](crate::client::Client::download), see [DownloadManager](crate::client::download::DownloadManager).
+    pub fn with_download_manager(mut self, download_manager: DownloadManager) -> Self {
+        self.download_manager = Some(download_manager);
+        self
+    }
+
+    /// Tracks uploads, see [UploadTracker](crate::client::upload::UploadTracker).
+    pub fn with_upload_tracker(mut self, upload_tracker: UploadTracker) -> Self {
+        self.upload_tracker = Some(upload_tracker);
+        self
+    }
+
+    /// Generates files sent with [InputFileGenerated](crate::types::InputFileGenerated), see [FileGenerators](crate::client::generation::FileGenerators).
+    pub fn with_file_generators(mut self, file_generators: FileGenerators) -> Self {
+        self.file_generators = Some(file_generators);
+        self
+    }
+
     #[doc(hidden)]
     pub fn with_tdlib_client<T: TdLibClient + Clone>(self, tdlib_client: T) -> ClientBuilder<T> {
         ClientBuilder {
@@ -158,6 +439,14 @@ where
             updates_sender: self.updates_sender,
             tdlib_parameters: self.tdlib_parameters,
             auth_state_channel_size: self.auth_state_channel_size,
+            proxy_manager: self.proxy_manager,
+            request_cache: self.request_cache,
+            store: self.store,
+            chat_lists: self.chat_lists,
+            message_cache: self.message_cache,
+            download_manager: self.download_manager,
+            upload_tracker: self.upload_tracker,
+            file_generators: self.file_generators,
         }
     }
 
@@ -166,12 +455,20 @@ where
             return Err(Error::BadRequest("tdlib_parameters not set"));
         };
 
-        let client = Client::new(
+        let mut client = Client::new(
             self.tdlib_client,
             self.updates_sender,
             self.tdlib_parameters.unwrap(),
             self.auth_state_channel_size,
         );
+        client.proxy_manager = self.proxy_manager;
+        client.request_cache = self.request_cache;
+        client.store = self.store;
+        client.chat_lists = self.chat_lists;
+        client.message_cache = self.message_cache;
+        client.download_manager = self.download_manager;
+        client.upload_tracker = self.upload_tracker;
+        client.file_generators = self.file_generators;
         Ok(client)
     }
 }
@@ -201,6 +498,17 @@ where
             auth_state_channel_size,
             is_started: false,
             client_id: None,
+            status: Arc::new(StatusSender::new()),
+            proxy_manager: None,
+            executor: None,
+            request_cache: None,
+            store: None,
+            chat_lists: None,
+            message_cache: None,
+            download_manager: None,
+            upload_tracker: None,
+            file_generators: None,
+            options: Options::new(),
         }
     }
 
@@ -224,6 +532,22 @@ where
     async fn make_request<T: RFunction, P: AsRef<T>, Q: DeserializeOwned>(
         &self,
         param: P,
+    ) -> Result<Q> {
+        if let Some(cache) = &self.request_cache {
+            let request = serde_json::to_value(param.as_ref())?;
+            if let Some(key) = cache.key(&request) {
+                let response = cache
+                    .get_or_fetch(key, &request, self.send_request::<T, P, Value>(param))
+                    .await?;
+                return Ok(serde_json::from_value(response)?);
+            }
+        }
+        self.send_request(param).await
+    }
+
+    async fn send_request<T: RFunction, P: AsRef<T>, Q: DeserializeOwned>(
+        &self,
+        param: P,
     ) -> Result<Q> {
         let extra = param.as_ref().extra().ok_or(NO_EXTRA)?;
         let signal = OBSERVER.subscribe(extra);
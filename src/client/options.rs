@@ -0,0 +1,317 @@
+//! Typed access to [TDLib options](https://core.telegram.org/tdlib/options).
+//!
+//! Every [Client](crate::client::Client) keeps options received with [UpdateOption](crate::types::UpdateOption) in [Options](crate::client::options::Options).
+//! Well-known options have typed accessors and keys, so option names and value types are checked by the compiler.
+//! ```no_run
+//! # use rust_tdlib::client::{options::keys, Client, tdlib_client::TdJson};
+//! # async fn run(client: Client<TdJson>) -> rust_tdlib::errors::Result<()> {
+//! let my_id: Option<i64> = client.options().my_id();
+//! let max_length = client.options().get(keys::MESSAGE_TEXT_LENGTH_MAX);
+//! client.set_option_value(keys::ONLINE, true).await?;
+//! # Ok(())
+//! # }
+//! ```
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::Result;
+use crate::types::{
+    Ok, OptionValue, OptionValueBoolean, OptionValueEmpty, OptionValueInteger, OptionValueString,
+    SetOption,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+
+/// Rust type of an option value.
+pub trait OptionType: Sized {
+    fn from_option_value(value: &OptionValue) -> Option<Self>;
+
+    fn into_option_value(self) -> OptionValue;
+}
+
+impl OptionType for bool {
+    fn from_option_value(value: &OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::Boolean(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    fn into_option_value(self) -> OptionValue {
+        OptionValue::Boolean(OptionValueBoolean::builder().value(self).build())
+    }
+}
+
+impl OptionType for i64 {
+    fn from_option_value(value: &OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::Integer(value) => Some(value.value()),
+            _ => None,
+        }
+    }
+
+    fn into_option_value(self) -> OptionValue {
+        OptionValue::Integer(OptionValueInteger::builder().value(self).build())
+    }
+}
+
+impl OptionType for String {
+    fn from_option_value(value: &OptionValue) -> Option<Self> {
+        match value {
+            OptionValue::String(value) => Some(value.value().clone()),
+            _ => None,
+        }
+    }
+
+    fn into_option_value(self) -> OptionValue {
+        OptionValue::String(OptionValueString::builder().value(self).build())
+    }
+}
+
+/// Marks keys of options which can only be read.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOnly;
+
+/// Marks keys of options which can be changed with [Client::set_option_value](crate::client::Client::set_option_value).
+#[derive(Debug, Clone, Copy)]
+pub struct Writable;
+
+/// Name of an option together with type of its value; `A` is [ReadOnly](crate::client::options::ReadOnly) or [Writable](crate::client::options::Writable).
+#[derive(Debug)]
+pub struct OptionKey<T, A = ReadOnly> {
+    name: &'static str,
+    _type: PhantomData<fn() -> (T, A)>,
+}
+
+impl<T, A> Clone for OptionKey<T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A> Copy for OptionKey<T, A> {}
+
+impl<T: OptionType, A> OptionKey<T, A> {
+    /// Key of an option which has no predefined constant in [keys](crate::client::options::keys),
+    /// for example `OptionKey::<bool, Writable>::new("is_location_visible")`.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _type: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+/// Keys of well-known options. Writable options can be changed with [Client::set_option_value](crate::client::Client::set_option_value).
+pub mod keys {
+    use super::{OptionKey, Writable};
+
+    // read-only options
+    pub const MY_ID: OptionKey<i64> = OptionKey::new("my_id");
+    pub const VERSION: OptionKey<String> = OptionKey::new("version");
+    pub const COMMIT_HASH: OptionKey<String> = OptionKey::new("commit_hash");
+    pub const IS_PREMIUM: OptionKey<bool> = OptionKey::new("is_premium");
+    pub const IS_PREMIUM_AVAILABLE: OptionKey<bool> = OptionKey::new("is_premium_available");
+    pub const UNIX_TIME: OptionKey<i64> = OptionKey::new("unix_time");
+    pub const UTC_TIME_OFFSET: OptionKey<i64> = OptionKey::new("utc_time_offset");
+    pub const AUTHORIZATION_DATE: OptionKey<i64> = OptionKey::new("authorization_date");
+    pub const TEST_MODE: OptionKey<bool> = OptionKey::new("test_mode");
+    pub const MESSAGE_TEXT_LENGTH_MAX: OptionKey<i64> = OptionKey::new("message_text_length_max");
+    pub const MESSAGE_CAPTION_LENGTH_MAX: OptionKey<i64> =
+        OptionKey::new("message_caption_length_max");
+    pub const FORWARDED_MESSAGE_COUNT_MAX: OptionKey<i64> =
+        OptionKey::new("forwarded_message_count_max");
+    pub const BASIC_GROUP_SIZE_MAX: OptionKey<i64> = OptionKey::new("basic_group_size_max");
+    pub const SUPERGROUP_SIZE_MAX: OptionKey<i64> = OptionKey::new("supergroup_size_max");
+    pub const PINNED_CHAT_COUNT_MAX: OptionKey<i64> = OptionKey::new("pinned_chat_count_max");
+    pub const PINNED_ARCHIVED_CHAT_COUNT_MAX: OptionKey<i64> =
+        OptionKey::new("pinned_archived_chat_count_max");
+    pub const TELEGRAM_SERVICE_NOTIFICATIONS_CHAT_ID: OptionKey<i64> =
+        OptionKey::new("telegram_service_notifications_chat_id");
+    pub const REPLIES_BOT_CHAT_ID: OptionKey<i64> = OptionKey::new("replies_bot_chat_id");
+
+    // writable options
+    pub const ONLINE: OptionKey<bool, Writable> = OptionKey::new("online");
+    pub const LANGUAGE_PACK_ID: OptionKey<String, Writable> = OptionKey::new("language_pack_id");
+    pub const LOCALIZATION_TARGET: OptionKey<String, Writable> =
+        OptionKey::new("localization_target");
+    pub const NOTIFICATION_GROUP_COUNT_MAX: OptionKey<i64, Writable> =
+        OptionKey::new("notification_group_count_max");
+    pub const NOTIFICATION_GROUP_SIZE_MAX: OptionKey<i64, Writable> =
+        OptionKey::new("notification_group_size_max");
+    pub const IGNORE_BACKGROUND_UPDATES: OptionKey<bool, Writable> =
+        OptionKey::new("ignore_background_updates");
+    pub const IGNORE_INLINE_THUMBNAILS: OptionKey<bool, Writable> =
+        OptionKey::new("ignore_inline_thumbnails");
+    pub const IGNORE_PLATFORM_RESTRICTIONS: OptionKey<bool, Writable> =
+        OptionKey::new("ignore_platform_restrictions");
+    pub const DISABLE_CONTACT_REGISTERED_NOTIFICATIONS: OptionKey<bool, Writable> =
+        OptionKey::new("disable_contact_registered_notifications");
+    pub const DISABLE_TOP_CHATS: OptionKey<bool, Writable> = OptionKey::new("disable_top_chats");
+    pub const USE_STORAGE_OPTIMIZER: OptionKey<bool, Writable> =
+        OptionKey::new("use_storage_optimizer");
+    pub const USE_QUICK_ACK: OptionKey<bool, Writable> = OptionKey::new("use_quick_ack");
+    pub const PREFER_IPV6: OptionKey<bool, Writable> = OptionKey::new("prefer_ipv6");
+    pub const STORAGE_MAX_FILES_SIZE: OptionKey<i64, Writable> =
+        OptionKey::new("storage_max_files_size");
+    pub const STORAGE_MAX_TIME_FROM_LAST_ACCESS: OptionKey<i64, Writable> =
+        OptionKey::new("storage_max_time_from_last_access");
+}
+
+/// Values of options received with [UpdateOption](crate::types::UpdateOption) of particular client.
+/// Options which were reset to their default values are not kept.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    values: Arc<RwLock<HashMap<String, OptionValue>>>,
+}
+
+impl Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns value of the option if it's known and has expected type.
+    pub fn get<T: OptionType, A>(&self, key: OptionKey<T, A>) -> Option<T> {
+        self.values
+            .read()
+            .unwrap()
+            .get(key.name)
+            .and_then(T::from_option_value)
+    }
+
+    /// Returns raw value of the option.
+    pub fn value(&self, name: &str) -> Option<OptionValue> {
+        self.values.read().unwrap().get(name).cloned()
+    }
+
+    /// Returns raw values of all known options.
+    pub fn values(&self) -> HashMap<String, OptionValue> {
+        self.values.read().unwrap().clone()
+    }
+
+    pub fn my_id(&self) -> Option<i64> {
+        self.get(keys::MY_ID)
+    }
+
+    pub fn version(&self) -> Option<String> {
+        self.get(keys::VERSION)
+    }
+
+    pub fn commit_hash(&self) -> Option<String> {
+        self.get(keys::COMMIT_HASH)
+    }
+
+    pub fn is_premium(&self) -> Option<bool> {
+        self.get(keys::IS_PREMIUM)
+    }
+
+    pub fn unix_time(&self) -> Option<i64> {
+        self.get(keys::UNIX_TIME)
+    }
+
+    pub fn message_text_length_max(&self) -> Option<i64> {
+        self.get(keys::MESSAGE_TEXT_LENGTH_MAX)
+    }
+
+    pub fn message_caption_length_max(&self) -> Option<i64> {
+        self.get(keys::MESSAGE_CAPTION_LENGTH_MAX)
+    }
+
+    pub fn forwarded_message_count_max(&self) -> Option<i64> {
+        self.get(keys::FORWARDED_MESSAGE_COUNT_MAX)
+    }
+
+    pub fn supergroup_size_max(&self) -> Option<i64> {
+        self.get(keys::SUPERGROUP_SIZE_MAX)
+    }
+
+    /// Sets values which are not received from TDLib yet.
+    pub(crate) fn restore(&self, values: &HashMap<String, OptionValue>) {
+        let mut current = self.values.write().unwrap();
+        for (name, value) in values {
+            current.entry(name.clone()).or_insert_with(|| value.clone());
+        }
+    }
+
+    /// Applies raw TDLib update.
+    pub(crate) fn apply(&self, update: &Value) {
+        if update.get("@type").and_then(|t| t.as_str()) != Some("updateOption") {
+            return;
+        }
+        let name = match update.get("name").and_then(|n| n.as_str()) {
+            None => return,
+            Some(name) => name.to_string(),
+        };
+        let mut values = self.values.write().unwrap();
+        // optionValueEmpty means that the option was reset to its default value
+        match update.get("value").cloned().map(serde_json::from_value) {
+            Some(Ok(OptionValue::Empty(_))) | None => {
+                values.remove(&name);
+            }
+            Some(Ok(value)) => {
+                values.insert(name, value);
+            }
+            Some(Err(err)) => log::error!("can't deserialize option {}: {}", name, err),
+        }
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Sets value of a writable option with [SetOption](crate::types::SetOption). Read-only options can't be set:
+    /// ```compile_fail
+    /// # use rust_tdlib::client::{options::keys, Client, tdlib_client::TdJson};
+    /// # async fn run(client: Client<TdJson>) {
+    /// client.set_option_value(keys::MY_ID, 1).await;
+    /// # }
+    /// ```
+    pub async fn set_option_value<T: OptionType>(
+        &self,
+        key: OptionKey<T, Writable>,
+        value: T,
+    ) -> Result<Ok> {
+        let request = SetOption::builder()
+            .name(key.name)
+            .value(value.into_option_value())
+            .build();
+        self.set_option(request).await
+    }
+
+    /// Resets a writable option to its default value.
+    pub async fn reset_option<T: OptionType>(&self, key: OptionKey<T, Writable>) -> Result<Ok> {
+        let request = SetOption::builder()
+            .name(key.name)
+            .value(OptionValue::Empty(OptionValueEmpty::builder().build()))
+            .build();
+        self.set_option(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{keys, OptionKey, Options};
+    use serde_json::json;
+
+    #[test]
+    fn typed_options() {
+        let options = Options::new();
+        options.apply(&json!({"@type": "updateOption", "name": "my_id", "value": {"@type": "optionValueInteger", "value": "42"}}));
+        options.apply(&json!({"@type": "updateOption", "name": "version", "value": {"@type": "optionValueString", "value": "1.8.0"}}));
+        options.apply(&json!({"@type": "updateOption", "name": "is_premium", "value": {"@type": "optionValueBoolean", "value": true}}));
+        assert_eq!(options.my_id(), Some(42));
+        assert_eq!(options.version().as_deref(), Some("1.8.0"));
+        assert_eq!(options.is_premium(), Some(true));
+        assert_eq!(options.get(OptionKey::<bool>::new("my_id")), None);
+        assert_eq!(options.get(keys::UNIX_TIME), None);
+
+        options.apply(&json!({"@type": "updateOption", "name": "is_premium", "value": {"@type": "optionValueEmpty"}}));
+        assert!(options.value("is_premium").is_none());
+    }
+}
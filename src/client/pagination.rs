@@ -0,0 +1,33 @@
+use crate::errors::Result;
+use futures::stream::{self, Stream, StreamExt};
+
+/// Source of pages for [paginate].
+pub(crate) trait Pager {
+    type Item;
+
+    /// Returns `true` if there are no more pages.
+    fn is_done(&self) -> bool;
+
+    async fn next_page(&mut self) -> Result<Vec<Self::Item>>;
+}
+
+/// Returns stream of items of all pages. The stream ends when the pager is done or after the first error, which is yielded as an item.
+pub(crate) fn paginate<P: Pager>(pager: P) -> impl Stream<Item = Result<P::Item>> {
+    let pages = stream::unfold(Some(pager), |pager| async move {
+        let mut pager = pager?;
+        if pager.is_done() {
+            return None;
+        }
+        match pager.next_page().await {
+            Ok(page) => Some((Ok(page), Some(pager))),
+            Err(err) => Some((Err(err), None)),
+        }
+    });
+    pages.flat_map(|page| {
+        let items: Vec<Result<P::Item>> = match page {
+            Ok(items) => items.into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        };
+        stream::iter(items)
+    })
+}
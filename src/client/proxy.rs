@@ -0,0 +1,506 @@
+use super::connection::ConnectionStateKind;
+use super::executor::{self, Executor, TaskHandle};
+use super::proxy_url::parse_proxy_url;
+use super::tdlib_client::TdLibClient;
+use super::{seconds, Client, ClientState};
+use crate::errors::Result;
+use crate::types::{AddProxy, EnableProxy, PingProxy, Proxy};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Shorter interval would make health checks flood TDLib with pings.
+const MIN_CHECK_INTERVAL: f64 = 1.;
+// Shorter timeout would switch proxies before TDLib has a chance to connect.
+const MIN_CONNECTING_TIMEOUT: f64 = 1.;
+
+/// Latency statistics of particular proxy, collected by [ProxyManager](crate::client::proxy::ProxyManager).
+#[derive(Debug, Clone, Default)]
+pub struct ProxyStats {
+    checks: usize,
+    failures: usize,
+    consecutive_failures: usize,
+    last_latency: Option<Duration>,
+    min_latency: Option<Duration>,
+    max_latency: Option<Duration>,
+    total_latency: Duration,
+}
+
+impl ProxyStats {
+    /// Total number of health checks.
+    pub fn checks(&self) -> usize {
+        self.checks
+    }
+
+    /// Total number of failed health checks.
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    /// Number of health checks failed in a row, zero if the last check succeeded.
+    pub fn consecutive_failures(&self) -> usize {
+        self.consecutive_failures
+    }
+
+    /// Latency measured by the last successful check.
+    pub fn last_latency(&self) -> Option<Duration> {
+        self.last_latency
+    }
+
+    /// The lowest latency of all successful checks.
+    pub fn min_latency(&self) -> Option<Duration> {
+        self.min_latency
+    }
+
+    /// The highest latency of all successful checks.
+    pub fn max_latency(&self) -> Option<Duration> {
+        self.max_latency
+    }
+
+    /// Average latency of all successful checks.
+    pub fn average_latency(&self) -> Option<Duration> {
+        match self.checks - self.failures {
+            0 => None,
+            succeeded => Some(self.total_latency / succeeded as u32),
+        }
+    }
+
+    fn record_success(&mut self, latency: Duration) {
+        self.checks += 1;
+        self.consecutive_failures = 0;
+        self.last_latency = Some(latency);
+        self.total_latency += latency;
+        self.min_latency = Some(self.min_latency.map_or(latency, |min| min.min(latency)));
+        self.max_latency = Some(self.max_latency.map_or(latency, |max| max.max(latency)));
+    }
+
+    fn record_failure(&mut self) {
+        self.checks += 1;
+        self.failures += 1;
+        self.consecutive_failures += 1;
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ProxyManagerSettings {
+    proxies: Vec<AddProxy>,
+    check_interval: Duration,
+    max_failures: usize,
+    max_latency: Option<Duration>,
+    connecting_timeout: Duration,
+}
+
+#[derive(Debug)]
+struct ProxyEntry {
+    proxy: Proxy,
+    stats: ProxyStats,
+}
+
+#[derive(Debug, Default)]
+struct ProxyPool {
+    entries: Vec<ProxyEntry>,
+    active: Option<usize>,
+    switched_at: Option<Instant>,
+    task: Option<TaskHandle>,
+}
+
+impl ProxyPool {
+    fn is_degraded(&self, idx: usize, settings: &ProxyManagerSettings) -> bool {
+        let stats = &self.entries[idx].stats;
+        stats.consecutive_failures >= settings.max_failures
+            || matches!(
+                (stats.last_latency, settings.max_latency),
+                (Some(latency), Some(max_latency)) if latency > max_latency
+            )
+    }
+
+    // Returns healthy proxy with the lowest average latency; if there is no such proxy, the one next to the active.
+    fn next_proxy(&self, settings: &ProxyManagerSettings) -> Option<usize> {
+        let active = self.active?;
+        let healthy = (0..self.entries.len())
+            .filter(|idx| *idx != active && !self.is_degraded(*idx, settings))
+            .min_by_key(|idx| {
+                self.entries[*idx]
+                    .stats
+                    .average_latency()
+                    .unwrap_or(Duration::MAX)
+            });
+        match healthy {
+            Some(idx) => Some(idx),
+            None if self.entries.len() > 1 => Some((active + 1) % self.entries.len()),
+            None => None,
+        }
+    }
+}
+
+/// Manages a pool of proxies for particular client.
+/// Proxies are checked periodically with [PingProxy](crate::types::PingProxy); when the active proxy degrades,
+/// or client gets stuck in [ConnectingToProxy](crate::types::ConnectionStateConnectingToProxy) state, manager switches to the healthiest one.
+/// Use [ClientBuilder::with_proxy_manager](crate::client::ClientBuilder::with_proxy_manager) to attach manager to the client; it will be started by [Worker::bind_client](crate::client::worker::Worker::bind_client).
+#[derive(Debug, Clone)]
+pub struct ProxyManager {
+    settings: Arc<ProxyManagerSettings>,
+    pool: Arc<Mutex<ProxyPool>>,
+}
+
+impl ProxyManager {
+    pub fn builder() -> ProxyManagerBuilder {
+        ProxyManagerBuilder::default()
+    }
+
+    /// Returns all added proxies with their statistics.
+    pub fn proxies(&self) -> Vec<(Proxy, ProxyStats)> {
+        self.pool
+            .lock()
+            .unwrap()
+            .entries
+            .iter()
+            .map(|entry| (entry.proxy.clone(), entry.stats.clone()))
+            .collect()
+    }
+
+    /// Returns currently enabled proxy.
+    pub fn active_proxy(&self) -> Option<Proxy> {
+        let pool = self.pool.lock().unwrap();
+        pool.active.map(|idx| pool.entries[idx].proxy.clone())
+    }
+
+    /// Stops health checks. Enabled proxy remains enabled.
+    pub fn stop(&self) {
+        if let Some(task) = self.pool.lock().unwrap().task.take() {
+            task.abort();
+        }
+    }
+
+    /// Adds all proxies to TDLib, enables the first one and starts health checks.
+    pub(crate) async fn start<R>(
+        &self,
+        client: &Client<R>,
+        executor: Arc<dyn Executor>,
+    ) -> Result<()>
+    where
+        R: TdLibClient + Clone + Send + Sync + 'static,
+    {
+        let mut entries = Vec::with_capacity(self.settings.proxies.len());
+        for proxy in self.settings.proxies.iter() {
+            let added = client
+                .add_proxy(
+                    AddProxy::builder()
+                        .server(proxy.server())
+                        .port(proxy.port())
+                        .type_(proxy.type_())
+                        .enable(false)
+                        .build(),
+                )
+                .await?;
+            log::debug!("proxy {}:{} added", added.server(), added.port());
+            entries.push(ProxyEntry {
+                proxy: added,
+                stats: ProxyStats::default(),
+            });
+        }
+        if entries.is_empty() {
+            return Ok(());
+        }
+        client
+            .enable_proxy(
+                EnableProxy::builder()
+                    .proxy_id(entries[0].proxy.id())
+                    .build(),
+            )
+            .await?;
+
+        let manager = self.clone();
+        let client = client.clone();
+        let mut pool = self.pool.lock().unwrap();
+        pool.entries = entries;
+        pool.active = Some(0);
+        pool.switched_at = Some(Instant::now());
+        let task = executor::spawn(executor.clone().as_ref(), async move {
+            manager.run(client, executor).await
+        });
+        if let Some(previous) = pool.task.replace(task) {
+            previous.abort();
+        }
+        Ok(())
+    }
+
+    async fn run<R>(self, client: Client<R>, executor: Arc<dyn Executor>)
+    where
+        R: TdLibClient + Clone + Send + Sync + 'static,
+    {
+        loop {
+            executor.sleep(self.settings.check_interval).await;
+            if client.status().client_state() == Some(ClientState::Closed) {
+                log::debug!("client closed, stop proxies health checks");
+                return;
+            }
+            self.check_proxies(&client).await;
+            if let Err(err) = self.switch_if_needed(&client).await {
+                log::error!("can't switch proxy: {}", err);
+            }
+        }
+    }
+
+    async fn check_proxies<R>(&self, client: &Client<R>)
+    where
+        R: TdLibClient + Clone,
+    {
+        let proxy_ids: Vec<i32> = self
+            .pool
+            .lock()
+            .unwrap()
+            .entries
+            .iter()
+            .map(|entry| entry.proxy.id())
+            .collect();
+        for proxy_id in proxy_ids {
+            let result = client
+                .ping_proxy(PingProxy::builder().proxy_id(proxy_id).build())
+                .await;
+            // proxies may be replaced by restart while the check is in progress
+            let mut pool = self.pool.lock().unwrap();
+            let stats = match pool
+                .entries
+                .iter_mut()
+                .find(|entry| entry.proxy.id() == proxy_id)
+            {
+                None => continue,
+                Some(entry) => &mut entry.stats,
+            };
+            match result {
+                Ok(seconds) => {
+                    stats.record_success(Duration::from_secs_f32(seconds.seconds().max(0.)))
+                }
+                Err(err) => {
+                    log::warn!("proxy {} check failed: {}", proxy_id, err);
+                    stats.record_failure()
+                }
+            }
+        }
+    }
+
+    async fn switch_if_needed<R>(&self, client: &Client<R>) -> Result<()>
+    where
+        R: TdLibClient + Clone,
+    {
+        let connection_stats = client.connection_stats();
+        let next = {
+            let pool = self.pool.lock().unwrap();
+            let active = match pool.active {
+                None => return Ok(()),
+                Some(active) => active,
+            };
+            let switched_long_ago = pool
+                .switched_at
+                .is_none_or(|at| at.elapsed() >= self.settings.connecting_timeout);
+            let stuck = switched_long_ago
+                && connection_stats.current() == Some(ConnectionStateKind::ConnectingToProxy)
+                && connection_stats.current_duration() >= self.settings.connecting_timeout;
+            if !stuck && !pool.is_degraded(active, &self.settings) {
+                return Ok(());
+            }
+            match pool.next_proxy(&self.settings) {
+                None => return Ok(()),
+                Some(next) => pool.entries[next].proxy.id(),
+            }
+        };
+
+        log::info!("active proxy degraded, switching to proxy {}", next);
+        client
+            .enable_proxy(EnableProxy::builder().proxy_id(next).build())
+            .await?;
+        let mut pool = self.pool.lock().unwrap();
+        if let Some(idx) = pool
+            .entries
+            .iter()
+            .position(|entry| entry.proxy.id() == next)
+        {
+            pool.active = Some(idx);
+            pool.switched_at = Some(Instant::now());
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct ProxyManagerBuilder {
+    settings: ProxyManagerSettings,
+}
+
+impl Default for ProxyManagerBuilder {
+    fn default() -> Self {
+        Self {
+            settings: ProxyManagerSettings {
+                proxies: Vec::new(),
+                check_interval: Duration::from_secs(60),
+                max_failures: 2,
+                max_latency: None,
+                connecting_timeout: Duration::from_secs(30),
+            },
+        }
+    }
+}
+
+impl ProxyManagerBuilder {
+    /// Adds proxy to the pool. The first added proxy will be enabled on start.
+    pub fn with_proxy(mut self, proxy: AddProxy) -> Self {
+        self.settings.proxies.push(proxy);
+        self
+    }
+
+    /// Parses proxy url (see [parse_proxy_url](crate::client::proxy_url::parse_proxy_url)) and adds it to the pool.
+    pub fn with_proxy_url<S: AsRef<str>>(self, url: S) -> Result<Self> {
+        Ok(self.with_proxy(parse_proxy_url(url)?))
+    }
+
+    /// Specifies interval between health checks, in seconds; at least 1. Default is 60.
+    pub fn with_check_interval(mut self, check_interval: f64) -> Self {
+        self.settings.check_interval = seconds::duration(check_interval, MIN_CHECK_INTERVAL)
+            .unwrap_or(Duration::from_secs_f64(MIN_CHECK_INTERVAL));
+        self
+    }
+
+    /// Specifies how many health checks have to fail in a row before proxy considered degraded. Default is 2.
+    pub fn with_max_failures(mut self, max_failures: usize) -> Self {
+        self.settings.max_failures = max_failures.max(1);
+        self
+    }
+
+    /// Proxy with greater latency, in seconds, will be considered degraded. Not limited by default.
+    /// Negative latency is treated as 0; NaN is ignored.
+    pub fn with_max_latency(mut self, max_latency: f64) -> Self {
+        if let Some(max_latency) = seconds::duration(max_latency, 0.) {
+            self.settings.max_latency = Some(max_latency);
+        }
+        self
+    }
+
+    /// Specifies how long, in seconds, client may stay in `ConnectingToProxy` state before proxy will be switched; at least 1. Default is 30.
+    /// NaN is ignored.
+    pub fn with_connecting_timeout(mut self, connecting_timeout: f64) -> Self {
+        if let Some(connecting_timeout) =
+            seconds::duration(connecting_timeout, MIN_CONNECTING_TIMEOUT)
+        {
+            self.settings.connecting_timeout = connecting_timeout;
+        }
+        self
+    }
+
+    pub fn build(self) -> ProxyManager {
+        ProxyManager {
+            settings: Arc::new(self.settings),
+            pool: Arc::new(Mutex::new(ProxyPool::default())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ProxyEntry, ProxyManager, ProxyStats};
+    use crate::client::mocked_raw_api::{error, MockedRawApi};
+    use crate::types::Proxy;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn entry(id: i32, latency_ms: u64, failures: usize) -> ProxyEntry {
+        let mut stats = ProxyStats::default();
+        stats.record_success(Duration::from_millis(latency_ms));
+        (0..failures).for_each(|_| stats.record_failure());
+        ProxyEntry {
+            proxy: Proxy::builder().id(id).build(),
+            stats,
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let mut stats = ProxyStats::default();
+        assert_eq!(stats.average_latency(), None);
+        stats.record_success(Duration::from_millis(100));
+        stats.record_failure();
+        stats.record_success(Duration::from_millis(300));
+        assert_eq!(stats.checks(), 3);
+        assert_eq!(stats.failures(), 1);
+        assert_eq!(stats.consecutive_failures(), 0);
+        assert_eq!(stats.last_latency(), Some(Duration::from_millis(300)));
+        assert_eq!(stats.min_latency(), Some(Duration::from_millis(100)));
+        assert_eq!(stats.max_latency(), Some(Duration::from_millis(300)));
+        assert_eq!(stats.average_latency(), Some(Duration::from_millis(200)));
+        stats.record_failure();
+        stats.record_failure();
+        assert_eq!(stats.consecutive_failures(), 2);
+    }
+
+    #[test]
+    fn next_proxy() {
+        let manager = ProxyManager::builder().with_max_latency(0.5).build();
+        let settings = manager.settings.clone();
+        let mut pool = manager.pool.lock().unwrap();
+        pool.entries = vec![entry(1, 100, 2), entry(2, 300, 0), entry(3, 200, 0)];
+        pool.active = Some(0);
+        assert!(pool.is_degraded(0, &settings));
+        assert_eq!(pool.next_proxy(&settings), Some(2));
+
+        pool.entries[2] = entry(3, 600, 0);
+        assert_eq!(pool.next_proxy(&settings), Some(1));
+
+        // all proxies degraded
+        pool.entries[1] = entry(2, 300, 2);
+        assert_eq!(pool.next_proxy(&settings), Some(1));
+        pool.active = Some(2);
+        assert_eq!(pool.next_proxy(&settings), Some(0));
+    }
+
+    #[tokio::test]
+    async fn check_proxies() {
+        let manager = ProxyManager::builder().build();
+        manager.pool.lock().unwrap().entries = vec![entry(1, 100, 0), entry(2, 100, 0)];
+        let api = MockedRawApi::new(vec![
+            json!({"@type": "seconds", "seconds": 0.25}),
+            error(400),
+        ]);
+        manager.check_proxies(&api.client()).await;
+
+        let proxy_ids: Vec<_> = api
+            .requests()
+            .iter()
+            .map(|r| r["proxy_id"].clone())
+            .collect();
+        assert_eq!(proxy_ids, vec![1, 2]);
+        let proxies = manager.proxies();
+        assert_eq!(
+            proxies[0].1.last_latency(),
+            Some(Duration::from_millis(250))
+        );
+        assert_eq!(proxies[1].1.consecutive_failures(), 1);
+    }
+
+    #[test]
+    fn check_interval_limited() {
+        for interval in [0., -1., f64::NAN] {
+            let manager = ProxyManager::builder()
+                .with_check_interval(interval)
+                .build();
+            assert_eq!(manager.settings.check_interval, Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn durations_validated() {
+        let manager = ProxyManager::builder()
+            .with_max_latency(-1.)
+            .with_connecting_timeout(0.)
+            .build();
+        assert_eq!(manager.settings.max_latency, Some(Duration::ZERO));
+        assert_eq!(manager.settings.connecting_timeout, Duration::from_secs(1));
+
+        let manager = ProxyManager::builder()
+            .with_max_latency(f64::NAN)
+            .with_connecting_timeout(f64::NAN)
+            .with_check_interval(f64::INFINITY)
+            .build();
+        assert_eq!(manager.settings.max_latency, None);
+        assert_eq!(manager.settings.connecting_timeout, Duration::from_secs(30));
+        assert_eq!(manager.settings.check_interval, Duration::MAX);
+    }
+}
@@ -0,0 +1,546 @@
+use super::chat_list::ChatListKind;
+use super::pagination::{paginate, Pager};
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{
+    GetChatMessageByDate, Message, MessageSender, Messages, SearchChatMessages, SearchMessages,
+    SearchMessagesFilter,
+};
+use futures::stream::Stream;
+
+// TDLib returns at most 100 messages per request
+const MAX_PAGE_SIZE: usize = 100;
+const NOT_FOUND_CODE: i32 = 404;
+
+/// Settings of [Client::search_messages_stream](crate::client::Client::search_messages_stream), which searches in all chats except secret ones.
+#[derive(Debug, Clone)]
+pub struct SearchOptions {
+    query: String,
+    chat_list: Option<ChatListKind>,
+    filter: SearchMessagesFilter,
+    min_date: i32,
+    max_date: i32,
+    page_size: usize,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            chat_list: None,
+            filter: SearchMessagesFilter::default(),
+            min_date: 0,
+            max_date: 0,
+            page_size: MAX_PAGE_SIZE,
+        }
+    }
+}
+
+impl SearchOptions {
+    pub fn builder() -> SearchOptionsBuilder {
+        SearchOptionsBuilder::default()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn chat_list(&self) -> Option<ChatListKind> {
+        self.chat_list
+    }
+
+    pub fn filter(&self) -> &SearchMessagesFilter {
+        &self.filter
+    }
+
+    pub fn min_date(&self) -> i32 {
+        self.min_date
+    }
+
+    pub fn max_date(&self) -> i32 {
+        self.max_date
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SearchOptionsBuilder {
+    inner: SearchOptions,
+}
+
+impl SearchOptionsBuilder {
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.inner.query = query.as_ref().to_string();
+        self
+    }
+
+    /// Search only in chats of the list; by default all chats are searched.
+    pub fn with_chat_list(mut self, chat_list: ChatListKind) -> Self {
+        self.inner.chat_list = Some(chat_list);
+        self
+    }
+
+    /// Filter of found messages. Filters which are specific to a chat, such as [Mention](crate::types::SearchMessagesFilter::Mention),
+    /// [UnreadMention](crate::types::SearchMessagesFilter::UnreadMention), [FailedToSend](crate::types::SearchMessagesFilter::FailedToSend)
+    /// or [Pinned](crate::types::SearchMessagesFilter::Pinned), aren't supported by TDLib here.
+    pub fn with_filter<T: AsRef<SearchMessagesFilter>>(mut self, filter: T) -> Self {
+        self.inner.filter = filter.as_ref().clone();
+        self
+    }
+
+    /// Skip messages sent before this Unix time.
+    pub fn with_min_date(mut self, min_date: i32) -> Self {
+        self.inner.min_date = min_date;
+        self
+    }
+
+    /// Skip messages sent after this Unix time.
+    pub fn with_max_date(mut self, max_date: i32) -> Self {
+        self.inner.max_date = max_date;
+        self
+    }
+
+    /// Number of messages requested at once. Default and maximum is 100.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.inner.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    pub fn build(self) -> SearchOptions {
+        self.inner
+    }
+}
+
+/// Settings of [Client::search_chat_messages_stream](crate::client::Client::search_chat_messages_stream).
+#[derive(Debug, Clone)]
+pub struct ChatSearchOptions {
+    query: String,
+    sender: Option<MessageSender>,
+    filter: SearchMessagesFilter,
+    message_thread_id: i64,
+    min_date: i32,
+    max_date: i32,
+    page_size: usize,
+}
+
+impl Default for ChatSearchOptions {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            sender: None,
+            filter: SearchMessagesFilter::default(),
+            message_thread_id: 0,
+            min_date: 0,
+            max_date: 0,
+            page_size: MAX_PAGE_SIZE,
+        }
+    }
+}
+
+impl ChatSearchOptions {
+    pub fn builder() -> ChatSearchOptionsBuilder {
+        ChatSearchOptionsBuilder::default()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn sender(&self) -> Option<&MessageSender> {
+        self.sender.as_ref()
+    }
+
+    pub fn filter(&self) -> &SearchMessagesFilter {
+        &self.filter
+    }
+
+    pub fn message_thread_id(&self) -> i64 {
+        self.message_thread_id
+    }
+
+    pub fn min_date(&self) -> i32 {
+        self.min_date
+    }
+
+    pub fn max_date(&self) -> i32 {
+        self.max_date
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ChatSearchOptionsBuilder {
+    inner: ChatSearchOptions,
+}
+
+impl ChatSearchOptionsBuilder {
+    pub fn with_query<T: AsRef<str>>(mut self, query: T) -> Self {
+        self.inner.query = query.as_ref().to_string();
+        self
+    }
+
+    /// Return only messages sent by the user or the chat.
+    pub fn with_sender<T: AsRef<MessageSender>>(mut self, sender: T) -> Self {
+        self.inner.sender = Some(sender.as_ref().clone());
+        self
+    }
+
+    /// Filter of found messages; any [SearchMessagesFilter](crate::types::SearchMessagesFilter) is supported.
+    pub fn with_filter<T: AsRef<SearchMessagesFilter>>(mut self, filter: T) -> Self {
+        self.inner.filter = filter.as_ref().clone();
+        self
+    }
+
+    /// Search only in the message thread.
+    pub fn with_message_thread_id(mut self, message_thread_id: i64) -> Self {
+        self.inner.message_thread_id = message_thread_id;
+        self
+    }
+
+    /// Stop at messages sent before this Unix time.
+    pub fn with_min_date(mut self, min_date: i32) -> Self {
+        self.inner.min_date = min_date;
+        self
+    }
+
+    /// Start from messages sent at or before this Unix time.
+    pub fn with_max_date(mut self, max_date: i32) -> Self {
+        self.inner.max_date = max_date;
+        self
+    }
+
+    /// Number of messages requested at once. Default and maximum is 100.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.inner.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    pub fn build(self) -> ChatSearchOptions {
+        self.inner
+    }
+}
+
+// Keeps messages which come after the last returned one, in order of TDLib, and finishes search on empty page.
+fn accept_page(
+    messages: Messages,
+    last: &mut Option<Message>,
+    done: &mut bool,
+    is_after_last: impl Fn(&Message, &Message) -> bool,
+) -> Vec<Message> {
+    let page: Vec<Message> = messages
+        .messages()
+        .iter()
+        .flatten()
+        .filter(|message| {
+            last.as_ref()
+                .is_none_or(|last| is_after_last(message, last))
+        })
+        .cloned()
+        .collect();
+    match page.last() {
+        None => *done = true,
+        Some(message) => *last = Some(message.clone()),
+    }
+    page
+}
+
+struct MessageSearch<'a, R>
+where
+    R: TdLibClient + Clone,
+{
+    client: &'a Client<R>,
+    options: SearchOptions,
+    last: Option<Message>,
+    done: bool,
+}
+
+impl<R> Pager for MessageSearch<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    type Item = Message;
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    async fn next_page(&mut self) -> Result<Vec<Message>> {
+        let mut request = SearchMessages::builder();
+        request
+            .query(&self.options.query)
+            .filter(&self.options.filter)
+            .min_date(self.options.min_date)
+            .max_date(self.options.max_date)
+            .limit(self.options.page_size as i32);
+        if let Some(chat_list) = self.options.chat_list {
+            request.chat_list(chat_list.chat_list());
+        }
+        if let Some(last) = &self.last {
+            request
+                .offset_date(last.date())
+                .offset_chat_id(last.chat_id())
+                .offset_message_id(last.id());
+        }
+        let messages = self.client.search_messages(request.build()).await?;
+        // results are sorted by decreasing (date, chat_id, message_id)
+        Ok(accept_page(
+            messages,
+            &mut self.last,
+            &mut self.done,
+            |message, last| {
+                (message.date(), message.chat_id(), message.id())
+                    < (last.date(), last.chat_id(), last.id())
+            },
+        ))
+    }
+}
+
+struct ChatMessageSearch<'a, R>
+where
+    R: TdLibClient + Clone,
+{
+    client: &'a Client<R>,
+    chat_id: i64,
+    options: ChatSearchOptions,
+    // the last message sent before max_date, from which the search starts
+    start_message_id: Option<i64>,
+    last: Option<Message>,
+    done: bool,
+}
+
+impl<R> ChatMessageSearch<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    // returns `None` if there are no messages sent before max_date
+    async fn find_start(&self) -> Result<Option<i64>> {
+        let request = GetChatMessageByDate::builder()
+            .chat_id(self.chat_id)
+            .date(self.options.max_date)
+            .build();
+        match self.client.get_chat_message_by_date(request).await {
+            Ok(message) => Ok(Some(message.id())),
+            Err(Error::TDLibError(err)) if err.code() == NOT_FOUND_CODE => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn request(&self) -> SearchChatMessages {
+        let mut request = SearchChatMessages::builder();
+        request
+            .chat_id(self.chat_id)
+            .query(&self.options.query)
+            .filter(&self.options.filter)
+            .message_thread_id(self.options.message_thread_id)
+            .limit(self.options.page_size as i32);
+        if let Some(sender) = &self.options.sender {
+            request.sender_id(sender);
+        }
+        match (&self.last, self.start_message_id) {
+            (Some(last), _) => {
+                request.from_message_id(last.id());
+            }
+            // offset must be greater than -limit; the starting message itself must be included
+            (None, Some(start_message_id)) => {
+                request
+                    .from_message_id(start_message_id)
+                    .offset(-1)
+                    .limit(self.options.page_size.max(2) as i32);
+            }
+            (None, None) => {}
+        }
+        request.build()
+    }
+}
+
+impl<R> Pager for ChatMessageSearch<'_, R>
+where
+    R: TdLibClient + Clone,
+{
+    type Item = Message;
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    async fn next_page(&mut self) -> Result<Vec<Message>> {
+        if self.last.is_none() && self.options.max_date != 0 {
+            self.start_message_id = self.find_start().await?;
+            if self.start_message_id.is_none() {
+                self.done = true;
+                return Ok(vec![]);
+            }
+        }
+        let messages = self.client.search_chat_messages(self.request()).await?;
+        // results are sorted by decreasing message_id
+        let mut page = accept_page(messages, &mut self.last, &mut self.done, |message, last| {
+            message.id() < last.id()
+        });
+        let (min_date, max_date) = (self.options.min_date, self.options.max_date);
+        if max_date != 0 {
+            page.retain(|message| message.date() <= max_date);
+        }
+        if min_date != 0 {
+            if let Some(stop) = page.iter().position(|message| message.date() < min_date) {
+                page.truncate(stop);
+                self.done = true;
+            }
+        }
+        Ok(page)
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Returns stream of messages found with [SearchMessages](crate::types::SearchMessages) in all chats, page by page,
+    /// in order of decreasing (date, chat_id, message_id). The stream ends when there are no more results or after an error.
+    pub fn search_messages_stream(
+        &self,
+        options: SearchOptions,
+    ) -> impl Stream<Item = Result<Message>> + '_ {
+        paginate(MessageSearch {
+            client: self,
+            options,
+            last: None,
+            done: false,
+        })
+    }
+
+    /// Returns stream of messages of the chat found with [SearchChatMessages](crate::types::SearchChatMessages), page by page,
+    /// in order of decreasing message_id. The stream ends when there are no more results, when [min date](crate::client::search::ChatSearchOptionsBuilder::with_min_date)
+    /// is reached or after an error.
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use rust_tdlib::client::{search::ChatSearchOptions, Client, tdlib_client::TdJson};
+    /// # use rust_tdlib::types::{SearchMessagesFilter, SearchMessagesFilterPhoto};
+    /// # async fn run(client: Client<TdJson>, chat_id: i64) -> rust_tdlib::errors::Result<()> {
+    /// let options = ChatSearchOptions::builder()
+    ///     .with_filter(SearchMessagesFilter::Photo(SearchMessagesFilterPhoto::builder().build()))
+    ///     .with_min_date(1_600_000_000)
+    ///     .build();
+    /// let mut photos = Box::pin(client.search_chat_messages_stream(chat_id, options));
+    /// while let Some(photo) = photos.next().await {
+    ///     println!("{}", photo?.id());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn search_chat_messages_stream(
+        &self,
+        chat_id: i64,
+        options: ChatSearchOptions,
+    ) -> impl Stream<Item = Result<Message>> + '_ {
+        paginate(ChatMessageSearch {
+            client: self,
+            chat_id,
+            options,
+            start_message_id: None,
+            last: None,
+            done: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChatSearchOptions, SearchOptions};
+    use crate::client::mocked_raw_api::{error, message, messages, MockedRawApi};
+    use crate::errors::Result;
+    use crate::types::Message;
+    use futures::StreamExt;
+
+    fn keys(messages: Vec<Result<Message>>) -> Vec<(i32, i64, i64)> {
+        messages
+            .into_iter()
+            .map(|m| m.unwrap())
+            .map(|m| (m.date(), m.chat_id(), m.id()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn global_search_cursor() {
+        // the second page repeats the last message of the first one
+        let api = MockedRawApi::new(vec![
+            messages(vec![
+                message(2, 5, 300),
+                message(1, 9, 300),
+                message(3, 1, 200),
+            ]),
+            messages(vec![message(3, 1, 200), message(1, 2, 100)]),
+            messages(vec![]),
+        ]);
+        let options = SearchOptions::builder().with_page_size(3).build();
+        let client = api.client();
+        let found = client.search_messages_stream(options).collect().await;
+        assert_eq!(
+            keys(found),
+            vec![(300, 2, 5), (300, 1, 9), (200, 3, 1), (100, 1, 2)]
+        );
+        assert_eq!(api.request_fields("offset_date"), vec![0, 200, 100]);
+        assert_eq!(api.request_fields("offset_chat_id"), vec![0, 3, 1]);
+        assert_eq!(api.request_fields("offset_message_id"), vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn chat_search_dates() {
+        // the first page may contain messages sent after max_date
+        let api = MockedRawApi::new(vec![
+            message(1, 50, 500),
+            messages(vec![
+                message(1, 51, 600),
+                message(1, 50, 500),
+                message(1, 40, 400),
+            ]),
+            messages(vec![
+                message(1, 40, 400),
+                message(1, 30, 300),
+                message(1, 20, 200),
+            ]),
+            messages(vec![]),
+        ]);
+        let options = ChatSearchOptions::builder()
+            .with_max_date(500)
+            .with_min_date(350)
+            .with_page_size(3)
+            .build();
+        let client = api.client();
+        let found = client.search_chat_messages_stream(1, options);
+        assert_eq!(
+            keys(found.collect().await),
+            vec![(500, 1, 50), (400, 1, 40)]
+        );
+        assert_eq!(
+            api.request_types(),
+            vec![
+                "getChatMessageByDate",
+                "searchChatMessages",
+                "searchChatMessages"
+            ]
+        );
+        assert_eq!(api.requests()[0]["date"], 500);
+        assert_eq!(api.request_fields("from_message_id")[1..], [50, 40]);
+        assert_eq!(api.request_fields("offset")[1..], [-1, 0]);
+    }
+
+    #[tokio::test]
+    async fn chat_search_nothing_before_max_date() {
+        let api = MockedRawApi::new(vec![error(404), messages(vec![message(1, 1, 1)])]);
+        let options = ChatSearchOptions::builder().with_max_date(500).build();
+        let client = api.client();
+        let found: Vec<_> = client
+            .search_chat_messages_stream(1, options)
+            .collect()
+            .await;
+        assert!(found.is_empty());
+        assert_eq!(api.request_types(), vec!["getChatMessageByDate"]);
+    }
+}
@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+// Converts builder setting in seconds to duration: values below `min` are raised to it, too large ones become `Duration::MAX`;
+// `None` for NaN, which is ignored by builders.
+pub(crate) fn duration(seconds: f64, min: f64) -> Option<Duration> {
+    if seconds.is_nan() {
+        return None;
+    }
+    Some(Duration::try_from_secs_f64(seconds.max(min)).unwrap_or(Duration::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::duration;
+    use std::time::Duration;
+
+    #[test]
+    fn seconds() {
+        assert_eq!(duration(1.5, 0.), Some(Duration::from_millis(1500)));
+        assert_eq!(duration(-1., 0.5), Some(Duration::from_millis(500)));
+        assert_eq!(duration(f64::INFINITY, 0.), Some(Duration::MAX));
+        assert_eq!(duration(f64::NAN, 0.), None);
+    }
+}
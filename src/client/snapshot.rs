@@ -0,0 +1,189 @@
+//! Persistable state of the client for warm starts.
+//!
+//! Snapshot contains chats, users and groups from [Store](crate::client::store::Store), chat positions from [ChatLists](crate::client::chat_list::ChatLists)
+//! and [Options](crate::client::options::Options).
+//! Save it before exit and restore it before the client is bound with [Worker](crate::client::worker::Worker),
+//! so the application is able to show data before TDLib sends its initial updates.
+//! Restored objects are replaced by the newer ones as updates arrive.
+//! ```no_run
+//! # use rust_tdlib::client::{snapshot::Snapshot, ChatLists, Client, Store};
+//! # fn run(tdlib_parameters: rust_tdlib::types::TdlibParameters) -> rust_tdlib::errors::Result<()> {
+//! let client = Client::builder()
+//!     .with_tdlib_parameters(tdlib_parameters)
+//!     .with_store(Store::new())
+//!     .with_chat_lists(ChatLists::new())
+//!     .build()?;
+//! if let Ok(snapshot) = Snapshot::load("state.bson") {
+//!     client.restore_snapshot(&snapshot);
+//! }
+//! // ... bind the client and work with it
+//! client.snapshot().save("state.bson")?;
+//! # Ok(())
+//! # }
+//! ```
+use super::chat_list::{ChatListEntry, ChatListKind};
+use super::tdlib_client::TdLibClient;
+use super::Client;
+use crate::errors::Result;
+use crate::types::{BasicGroup, Chat, OptionValue, SecretChat, Supergroup, User};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Chats, users, groups, options and chat list positions of the client, serialized to BSON.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub(crate) chats: Vec<Chat>,
+    pub(crate) users: Vec<User>,
+    pub(crate) basic_groups: Vec<BasicGroup>,
+    pub(crate) supergroups: Vec<Supergroup>,
+    pub(crate) secret_chats: Vec<SecretChat>,
+    pub(crate) options: HashMap<String, OptionValue>,
+    pub(crate) chat_lists: Vec<(ChatListKind, Vec<ChatListEntry>)>,
+}
+
+impl Snapshot {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(bson::from_slice(bytes)?)
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        Ok(bson::to_vec(self)?)
+    }
+
+    /// Reads snapshot from the file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+
+    /// Writes snapshot to the file. The file is replaced only after the whole snapshot is written.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let mut tmp_path = path.as_os_str().to_owned();
+        tmp_path.push(".tmp");
+        fs::write(&tmp_path, self.to_bytes()?)?;
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn chats(&self) -> &[Chat] {
+        &self.chats
+    }
+
+    pub fn users(&self) -> &[User] {
+        &self.users
+    }
+
+    pub fn basic_groups(&self) -> &[BasicGroup] {
+        &self.basic_groups
+    }
+
+    pub fn supergroups(&self) -> &[Supergroup] {
+        &self.supergroups
+    }
+
+    pub fn secret_chats(&self) -> &[SecretChat] {
+        &self.secret_chats
+    }
+
+    pub fn options(&self) -> &HashMap<String, OptionValue> {
+        &self.options
+    }
+
+    /// Returns saved chats of the list in their order.
+    pub fn chat_list(&self, list: ChatListKind) -> &[ChatListEntry] {
+        self.chat_lists
+            .iter()
+            .find(|(kind, _)| *kind == list)
+            .map(|(_, entries)| entries.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Returns snapshot of the [Options](crate::client::options::Options), [Store](crate::client::store::Store) and [ChatLists](crate::client::chat_list::ChatLists) of the client.
+    /// Parts which are not enabled for the client are empty.
+    pub fn snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot {
+            options: self.options().values(),
+            ..Default::default()
+        };
+        if let Some(store) = self.store() {
+            store.export(&mut snapshot);
+        }
+        if let Some(chat_lists) = self.chat_lists() {
+            chat_lists.export(&mut snapshot);
+        }
+        snapshot
+    }
+
+    /// Fills [Options](crate::client::options::Options), [Store](crate::client::store::Store) and [ChatLists](crate::client::chat_list::ChatLists) of the client with data from the snapshot.
+    /// Data already received from TDLib is not overwritten.
+    pub fn restore_snapshot(&self, snapshot: &Snapshot) {
+        self.options().restore(&snapshot.options);
+        if let Some(store) = self.store() {
+            store.restore(snapshot);
+        }
+        if let Some(chat_lists) = self.chat_lists() {
+            chat_lists.restore(snapshot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Snapshot;
+    use crate::client::{ChatListKind, ChatLists, Options, Store};
+    use serde_json::json;
+
+    #[test]
+    fn bson_roundtrip() {
+        let store = Store::new();
+        let chat_lists = ChatLists::new();
+        let position = json!({"@type": "chatPosition", "list": {"@type": "chatListMain"}, "order": "42", "is_pinned": true});
+        let update = json!({
+            "@type": "updateNewChat",
+            "chat": {
+                "@type": "chat",
+                "id": 10,
+                "title": "chat",
+                "type": {"@type": "chatTypePrivate", "user_id": 10},
+                "permissions": {"@type": "chatPermissions"},
+                "notification_settings": {"@type": "chatNotificationSettings"},
+                "video_chat": {"@type": "videoChat"},
+                "positions": [position]
+            }
+        });
+        store.apply(&update);
+        chat_lists.apply(&update);
+        store.apply(&json!({"@type": "updateUser", "user": {"@type": "user", "id": 10, "first_name": "a", "status": {"@type": "userStatusEmpty"}, "type": {"@type": "userTypeRegular"}}}));
+        let options = Options::new();
+        options.apply(&json!({"@type": "updateOption", "name": "my_id", "value": {"@type": "optionValueInteger", "value": "10"}}));
+
+        let mut snapshot = Snapshot {
+            options: options.values(),
+            ..Default::default()
+        };
+        store.export(&mut snapshot);
+        chat_lists.export(&mut snapshot);
+        let snapshot = Snapshot::from_bytes(&snapshot.to_bytes().unwrap()).unwrap();
+        assert_eq!(snapshot.chats().len(), 1);
+        assert_eq!(snapshot.users()[0].first_name(), "a");
+        assert_eq!(snapshot.chat_list(ChatListKind::Main).len(), 1);
+
+        let restored_store = Store::new();
+        let restored_lists = ChatLists::new();
+        let restored_options = Options::new();
+        restored_options.restore(&snapshot.options);
+        restored_store.restore(&snapshot);
+        restored_lists.restore(&snapshot);
+        assert_eq!(restored_store.chat(10).unwrap().title(), "chat");
+        assert_eq!(restored_options.my_id(), Some(10));
+        let entry = restored_lists.position(ChatListKind::Main, 10).unwrap();
+        assert_eq!(entry.order(), 42);
+        assert!(entry.is_pinned());
+    }
+}
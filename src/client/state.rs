@@ -0,0 +1,171 @@
+use super::connection::{ConnectionStateKind, ConnectionStats, ConnectionTransition};
+use super::ClientState;
+use crate::types::{AuthorizationState, ConnectionState};
+use std::sync::Mutex;
+use tokio::sync::{broadcast, watch};
+
+const CONNECTION_TRANSITIONS_CHANNEL_SIZE: usize = 16;
+
+/// Current state of particular client, see [Client::state_watcher](crate::client::Client::state_watcher).
+#[derive(Debug, Clone, Default)]
+pub struct ClientStatus {
+    authorization_state: Option<AuthorizationState>,
+    connection_state: Option<ConnectionState>,
+}
+
+impl ClientStatus {
+    /// Last [AuthorizationState](crate::types::AuthorizationState) received from TDLib, if any.
+    pub fn authorization_state(&self) -> Option<&AuthorizationState> {
+        self.authorization_state.as_ref()
+    }
+
+    /// Last [ConnectionState](crate::types::ConnectionState) received from TDLib, if any.
+    pub fn connection_state(&self) -> Option<&ConnectionState> {
+        self.connection_state.as_ref()
+    }
+
+    /// Short form of [authorization_state](crate::client::state::ClientStatus::authorization_state).
+    /// Returns `None` if TDLib did not send any authorization state yet.
+    pub fn client_state(&self) -> Option<ClientState> {
+        match self.authorization_state.as_ref()? {
+            AuthorizationState::_Default | AuthorizationState::GetAuthorizationState(_) => None,
+            AuthorizationState::Ready(_) => Some(ClientState::Opened),
+            AuthorizationState::Closed(_)
+            | AuthorizationState::Closing(_)
+            | AuthorizationState::LoggingOut(_) => Some(ClientState::Closed),
+            _ => Some(ClientState::Authorizing),
+        }
+    }
+
+    /// Determines that TDLib waits for data from the user: phone number, code, password and so on.
+    pub fn is_waiting_for_input(&self) -> bool {
+        matches!(
+            self.authorization_state,
+            Some(AuthorizationState::WaitCode(_))
+                | Some(AuthorizationState::WaitEncryptionKey(_))
+                | Some(AuthorizationState::WaitOtherDeviceConfirmation(_))
+                | Some(AuthorizationState::WaitPassword(_))
+                | Some(AuthorizationState::WaitPhoneNumber(_))
+                | Some(AuthorizationState::WaitRegistration(_))
+        )
+    }
+
+    /// Determines that there is a working connection to the Telegram servers.
+    pub fn is_online(&self) -> bool {
+        matches!(self.connection_state, Some(ConnectionState::Ready(_)))
+    }
+
+    /// Determines that the client is authorized and ready to use.
+    pub fn is_authorized(&self) -> bool {
+        matches!(self.authorization_state, Some(AuthorizationState::Ready(_)))
+    }
+}
+
+// Keeps the latest client status. Shared between all clones of the client.
+#[derive(Debug)]
+pub(crate) struct StatusSender {
+    sender: watch::Sender<ClientStatus>,
+    connection_stats: Mutex<ConnectionStats>,
+    connection_transitions: broadcast::Sender<ConnectionTransition>,
+}
+
+impl StatusSender {
+    pub fn new() -> Self {
+        let (sender, _) = watch::channel(ClientStatus::default());
+        let (connection_transitions, _) = broadcast::channel(CONNECTION_TRANSITIONS_CHANNEL_SIZE);
+        Self {
+            sender,
+            connection_stats: Mutex::new(ConnectionStats::default()),
+            connection_transitions,
+        }
+    }
+
+    pub fn connection_stats(&self) -> ConnectionStats {
+        self.connection_stats.lock().unwrap().clone()
+    }
+
+    pub fn subscribe_connection_transitions(&self) -> broadcast::Receiver<ConnectionTransition> {
+        self.connection_transitions.subscribe()
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<ClientStatus> {
+        self.sender.subscribe()
+    }
+
+    pub fn current(&self) -> ClientStatus {
+        self.sender.borrow().clone()
+    }
+
+    pub fn set_authorization_state(&self, authorization_state: AuthorizationState) {
+        self.sender
+            .send_modify(|status| status.authorization_state = Some(authorization_state));
+    }
+
+    pub fn set_connection_state(&self, connection_state: ConnectionState) {
+        if let Some(kind) = ConnectionStateKind::from_connection_state(&connection_state) {
+            let transition = self.connection_stats.lock().unwrap().apply(kind);
+            if let Some(transition) = transition {
+                log::debug!(
+                    "connection state changed: {:?} -> {:?}",
+                    transition.from(),
+                    transition.to()
+                );
+                // error means that nobody subscribed for transitions
+                let _ = self.connection_transitions.send(transition);
+            }
+        }
+        self.sender
+            .send_modify(|status| status.connection_state = Some(connection_state));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StatusSender;
+    use crate::client::connection::ConnectionStateKind;
+    use crate::client::ClientState;
+    use crate::types::{
+        AuthorizationState, AuthorizationStateReady, AuthorizationStateWaitCode, ConnectionState,
+        ConnectionStateConnecting, ConnectionStateReady,
+    };
+
+    #[test]
+    fn status_changes() {
+        let status = StatusSender::new();
+        let mut receiver = status.subscribe();
+        let mut transitions = status.subscribe_connection_transitions();
+        assert_eq!(receiver.borrow_and_update().client_state(), None);
+
+        status.set_authorization_state(AuthorizationState::WaitCode(
+            AuthorizationStateWaitCode::default(),
+        ));
+        assert!(receiver.has_changed().unwrap());
+        let current = receiver.borrow_and_update().clone();
+        assert_eq!(current.client_state(), Some(ClientState::Authorizing));
+        assert!(current.is_waiting_for_input());
+        assert!(!current.is_authorized());
+
+        status.set_connection_state(ConnectionState::Connecting(
+            ConnectionStateConnecting::default(),
+        ));
+        status.set_connection_state(ConnectionState::Ready(ConnectionStateReady::default()));
+        status
+            .set_authorization_state(AuthorizationState::Ready(AuthorizationStateReady::default()));
+        assert!(receiver.has_changed().unwrap());
+        let current = receiver.borrow_and_update().clone();
+        assert_eq!(current.client_state(), Some(ClientState::Opened));
+        assert!(current.is_authorized());
+        assert!(current.is_online());
+        assert!(!current.is_waiting_for_input());
+
+        let transition = transitions.try_recv().unwrap();
+        assert_eq!(transition.to(), ConnectionStateKind::Connecting);
+        let transition = transitions.try_recv().unwrap();
+        assert_eq!(transition.from(), Some(ConnectionStateKind::Connecting));
+        assert_eq!(transition.to(), ConnectionStateKind::Ready);
+        assert_eq!(
+            status.connection_stats().current(),
+            Some(ConnectionStateKind::Ready)
+        );
+    }
+}
@@ -0,0 +1,424 @@
+use super::chat_list::ChatListKind;
+use super::json::id;
+use super::snapshot::Snapshot;
+use crate::types::{BasicGroup, Chat, SecretChat, Supergroup, User};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use tokio::sync::broadcast;
+
+const CHANGES_CHANNEL_SIZE: usize = 256;
+
+// Fields of the chat which TDLib sends in `updateChat*` updates under the same names.
+const CHAT_FIELDS: [&str; 23] = [
+    "title",
+    "photo",
+    "permissions",
+    "last_message",
+    "positions",
+    "message_sender_id",
+    "has_protected_content",
+    "is_marked_as_unread",
+    "is_blocked",
+    "has_scheduled_messages",
+    "default_disable_notification",
+    "unread_count",
+    "last_read_inbox_message_id",
+    "last_read_outbox_message_id",
+    "unread_mention_count",
+    "notification_settings",
+    "message_ttl",
+    "theme_name",
+    "action_bar",
+    "video_chat",
+    "pending_join_requests",
+    "reply_markup_message_id",
+    "draft_message",
+];
+
+/// Object changed in the [Store](crate::client::store::Store). Use lookup methods of the store to get its current value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StoreChange {
+    Chat(i64),
+    User(i64),
+    BasicGroup(i64),
+    Supergroup(i64),
+    SecretChat(i64),
+}
+
+#[derive(Debug, Default)]
+struct StoreState {
+    chats: HashMap<i64, Chat>,
+    users: HashMap<i64, User>,
+    basic_groups: HashMap<i64, BasicGroup>,
+    supergroups: HashMap<i64, Supergroup>,
+    secret_chats: HashMap<i64, SecretChat>,
+}
+
+/// Keeps up-to-date chats, users, basic groups, supergroups, and secret chats, built from updates of particular client,
+/// such as [UpdateNewChat](crate::types::UpdateNewChat), [UpdateUser](crate::types::UpdateUser) or [UpdateChatLastMessage](crate::types::UpdateChatLastMessage).
+/// Use [ClientBuilder::with_store](crate::client::ClientBuilder::with_store) to enable it.
+#[derive(Debug, Clone)]
+pub struct Store {
+    state: Arc<RwLock<StoreState>>,
+    changes: broadcast::Sender<StoreChange>,
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        let (changes, _) = broadcast::channel(CHANGES_CHANNEL_SIZE);
+        Self {
+            state: Arc::new(RwLock::new(StoreState::default())),
+            changes,
+        }
+    }
+}
+
+fn object<T: DeserializeOwned>(update: &Value, key: &str) -> Option<(i64, T)> {
+    let value = update.get(key)?;
+    let object_id = id(value, "id")?;
+    match serde_json::from_value(value.clone()) {
+        Ok(object) => Some((object_id, object)),
+        Err(err) => {
+            log::error!("store can't deserialize {}: {}", key, err);
+            None
+        }
+    }
+}
+
+// Chat has no setters, so changed fields are merged into its json representation.
+fn merge_chat(chat: &Chat, update: &Value) -> Option<Chat> {
+    let update = update.as_object()?;
+    let is_position = update.get("@type").and_then(|t| t.as_str()) == Some("updateChatPosition");
+    if !is_position && !CHAT_FIELDS.iter().any(|field| update.contains_key(*field)) {
+        return None;
+    }
+    let mut value = serde_json::to_value(chat).ok()?;
+    let fields = value.as_object_mut()?;
+    if is_position {
+        merge_position(fields, update.get("position")?);
+    }
+    for field in CHAT_FIELDS {
+        if let Some(field_value) = update.get(field) {
+            fields.insert(field.to_string(), field_value.clone());
+        }
+    }
+    match serde_json::from_value(value) {
+        Ok(chat) => Some(chat),
+        Err(err) => {
+            log::error!("store can't apply chat update: {}", err);
+            None
+        }
+    }
+}
+
+// Replaces position of the chat in the same chat list; position with zero order means that chat was removed from the list.
+fn merge_position(chat: &mut Map<String, Value>, position: &Value) {
+    let mut positions = match chat.remove("positions") {
+        Some(Value::Array(positions)) => positions,
+        _ => vec![],
+    };
+    // serialized chat list may contain extra fields, so lists are compared by kind
+    let list = position.get("list").and_then(ChatListKind::from_value);
+    positions.retain(|p| p.get("list").and_then(ChatListKind::from_value) != list);
+    if id(position, "order").unwrap_or_default() != 0 {
+        positions.push(position.clone());
+    }
+    chat.insert("positions".to_string(), Value::Array(positions));
+}
+
+impl Store {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns [Receiver](tokio::sync::broadcast::Receiver) of changes. Only changes happened after the call will be received.
+    pub fn subscribe(&self) -> broadcast::Receiver<StoreChange> {
+        self.changes.subscribe()
+    }
+
+    pub fn chat(&self, chat_id: i64) -> Option<Chat> {
+        self.state.read().unwrap().chats.get(&chat_id).cloned()
+    }
+
+    pub fn user(&self, user_id: i64) -> Option<User> {
+        self.state.read().unwrap().users.get(&user_id).cloned()
+    }
+
+    pub fn basic_group(&self, basic_group_id: i64) -> Option<BasicGroup> {
+        self.state
+            .read()
+            .unwrap()
+            .basic_groups
+            .get(&basic_group_id)
+            .cloned()
+    }
+
+    pub fn supergroup(&self, supergroup_id: i64) -> Option<Supergroup> {
+        self.state
+            .read()
+            .unwrap()
+            .supergroups
+            .get(&supergroup_id)
+            .cloned()
+    }
+
+    pub fn secret_chat(&self, secret_chat_id: i64) -> Option<SecretChat> {
+        self.state
+            .read()
+            .unwrap()
+            .secret_chats
+            .get(&secret_chat_id)
+            .cloned()
+    }
+
+    /// Returns all known chats in arbitrary order.
+    pub fn chats(&self) -> Vec<Chat> {
+        self.state.read().unwrap().chats.values().cloned().collect()
+    }
+
+    /// Returns all known users in arbitrary order.
+    pub fn users(&self) -> Vec<User> {
+        self.state.read().unwrap().users.values().cloned().collect()
+    }
+
+    /// Copies current state into the snapshot.
+    pub(crate) fn export(&self, snapshot: &mut Snapshot) {
+        let state = self.state.read().unwrap();
+        snapshot.chats = state.chats.values().cloned().collect();
+        snapshot.users = state.users.values().cloned().collect();
+        snapshot.basic_groups = state.basic_groups.values().cloned().collect();
+        snapshot.supergroups = state.supergroups.values().cloned().collect();
+        snapshot.secret_chats = state.secret_chats.values().cloned().collect();
+    }
+
+    /// Fills the store with objects from the snapshot. Objects already received from TDLib are kept.
+    pub(crate) fn restore(&self, snapshot: &Snapshot) {
+        let mut changes = vec![];
+        {
+            let mut state = self.state.write().unwrap();
+            restore_objects(
+                &mut state.chats,
+                &snapshot.chats,
+                Chat::id,
+                StoreChange::Chat,
+                &mut changes,
+            );
+            restore_objects(
+                &mut state.users,
+                &snapshot.users,
+                User::id,
+                StoreChange::User,
+                &mut changes,
+            );
+            restore_objects(
+                &mut state.basic_groups,
+                &snapshot.basic_groups,
+                BasicGroup::id,
+                StoreChange::BasicGroup,
+                &mut changes,
+            );
+            restore_objects(
+                &mut state.supergroups,
+                &snapshot.supergroups,
+                Supergroup::id,
+                StoreChange::Supergroup,
+                &mut changes,
+            );
+            restore_objects(
+                &mut state.secret_chats,
+                &snapshot.secret_chats,
+                |c: &SecretChat| c.id() as i64,
+                StoreChange::SecretChat,
+                &mut changes,
+            );
+        }
+        for change in changes {
+            let _ = self.changes.send(change);
+        }
+    }
+
+    /// Applies raw TDLib update.
+    pub(crate) fn apply(&self, update: &Value) {
+        let type_ = match update.get("@type").and_then(|t| t.as_str()) {
+            None => return,
+            Some(type_) => type_,
+        };
+        let change = {
+            let mut state = self.state.write().unwrap();
+            match type_ {
+                "updateNewChat" => object(update, "chat").map(|(id, chat)| {
+                    state.chats.insert(id, chat);
+                    StoreChange::Chat(id)
+                }),
+                "updateUser" => object(update, "user").map(|(id, user)| {
+                    state.users.insert(id, user);
+                    StoreChange::User(id)
+                }),
+                "updateBasicGroup" => object(update, "basic_group").map(|(id, basic_group)| {
+                    state.basic_groups.insert(id, basic_group);
+                    StoreChange::BasicGroup(id)
+                }),
+                "updateSupergroup" => object(update, "supergroup").map(|(id, supergroup)| {
+                    state.supergroups.insert(id, supergroup);
+                    StoreChange::Supergroup(id)
+                }),
+                "updateSecretChat" => object(update, "secret_chat").map(|(id, secret_chat)| {
+                    state.secret_chats.insert(id, secret_chat);
+                    StoreChange::SecretChat(id)
+                }),
+                "updateUserStatus" => apply_user_status(&mut state, update).map(StoreChange::User),
+                _ if type_.starts_with("updateChat") || type_ == "updateMessageMentionRead" => {
+                    id(update, "chat_id").and_then(|chat_id| {
+                        let chat = merge_chat(state.chats.get(&chat_id)?, update)?;
+                        state.chats.insert(chat_id, chat);
+                        Some(StoreChange::Chat(chat_id))
+                    })
+                }
+                _ => None,
+            }
+        };
+        if let Some(change) = change {
+            // error means that nobody subscribed for changes
+            let _ = self.changes.send(change);
+        }
+    }
+}
+
+fn restore_objects<T: Clone>(
+    objects: &mut HashMap<i64, T>,
+    restored: &[T],
+    id: impl Fn(&T) -> i64,
+    change: fn(i64) -> StoreChange,
+    changes: &mut Vec<StoreChange>,
+) {
+    for object in restored {
+        let object_id = id(object);
+        if let Entry::Vacant(entry) = objects.entry(object_id) {
+            entry.insert(object.clone());
+            changes.push(change(object_id));
+        }
+    }
+}
+
+fn apply_user_status(state: &mut StoreState, update: &Value) -> Option<i64> {
+    let user_id = id(update, "user_id")?;
+    let mut user = serde_json::to_value(state.users.get(&user_id)?).ok()?;
+    user.as_object_mut()?
+        .insert("status".to_string(), update.get("status")?.clone());
+    match serde_json::from_value(user) {
+        Ok(user) => {
+            state.users.insert(user_id, user);
+            Some(user_id)
+        }
+        Err(err) => {
+            log::error!("store can't apply user status: {}", err);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Store, StoreChange};
+    use crate::types::{ChatList, UserStatus};
+    use serde_json::json;
+
+    fn chat_list_main() -> serde_json::Value {
+        json!({"@type": "chatListMain"})
+    }
+
+    #[test]
+    fn apply_updates() {
+        let store = Store::new();
+        let mut changes = store.subscribe();
+        store.apply(&json!({
+            "@type": "updateNewChat",
+            "chat": {
+                "@type": "chat",
+                "id": 10,
+                "title": "old",
+                "type": {"@type": "chatTypePrivate", "user_id": 10},
+                "permissions": {"@type": "chatPermissions"},
+                "notification_settings": {"@type": "chatNotificationSettings"},
+                "video_chat": {"@type": "videoChat"},
+                "positions": []
+            }
+        }));
+        store.apply(&json!({"@type": "updateChatTitle", "chat_id": 10, "title": "new"}));
+        store.apply(&json!({"@type": "updateChatReadInbox", "chat_id": 10, "last_read_inbox_message_id": 5, "unread_count": 3}));
+        store.apply(&json!({"@type": "updateChatPosition", "chat_id": 10, "position": {"@type": "chatPosition", "list": chat_list_main(), "order": "42", "is_pinned": false}}));
+        store.apply(&json!({"@type": "updateChatAction", "chat_id": 10, "message_thread_id": 0}));
+
+        let chat = store.chat(10).unwrap();
+        assert_eq!(chat.title(), "new");
+        assert_eq!(chat.unread_count(), 3);
+        assert_eq!(chat.last_read_inbox_message_id(), 5);
+        let positions = chat.positions().clone().unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].order(), 42);
+        assert!(matches!(positions[0].list(), ChatList::Main(_)));
+
+        store.apply(&json!({"@type": "updateChatPosition", "chat_id": 10, "position": {"@type": "chatPosition", "list": chat_list_main(), "order": "0", "is_pinned": false}}));
+        assert!(store
+            .chat(10)
+            .unwrap()
+            .positions()
+            .clone()
+            .unwrap()
+            .is_empty());
+
+        store.apply(&json!({"@type": "updateUser", "user": {"@type": "user", "id": 10, "first_name": "a", "status": {"@type": "userStatusEmpty"}, "type": {"@type": "userTypeRegular"}}}));
+        store.apply(&json!({"@type": "updateUserStatus", "user_id": 10, "status": {"@type": "userStatusOnline", "expires": 1}}));
+        assert!(matches!(
+            store.user(10).unwrap().status(),
+            UserStatus::Online(_)
+        ));
+
+        let received: Vec<StoreChange> = std::iter::from_fn(|| changes.try_recv().ok()).collect();
+        assert_eq!(
+            received
+                .iter()
+                .filter(|c| **c == StoreChange::Chat(10))
+                .count(),
+            5
+        );
+        assert_eq!(
+            received
+                .iter()
+                .filter(|c| **c == StoreChange::User(10))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn positions_in_folders() {
+        let store = Store::new();
+        store.apply(&json!({
+            "@type": "updateNewChat",
+            "chat": {
+                "@type": "chat",
+                "id": 10,
+                "type": {"@type": "chatTypePrivate", "user_id": 10},
+                "permissions": {"@type": "chatPermissions"},
+                "notification_settings": {"@type": "chatNotificationSettings"},
+                "video_chat": {"@type": "videoChat"},
+                "positions": []
+            }
+        }));
+        for (folder_id, order) in [(1, "1"), (2, "2"), (1, "0")] {
+            store.apply(&json!({"@type": "updateChatPosition", "chat_id": 10, "position": {"@type": "chatPosition", "list": {"@type": "chatListFolder", "chat_folder_id": folder_id}, "order": order, "is_pinned": false}}));
+        }
+
+        // removal from one folder keeps position in another one
+        let positions = store.chat(10).unwrap().positions().clone().unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].order(), 2);
+        assert!(
+            matches!(positions[0].list(), ChatList::Folder(folder) if folder.chat_folder_id() == 2)
+        );
+    }
+}
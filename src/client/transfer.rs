@@ -0,0 +1,87 @@
+use crate::errors::{Error, Result};
+use crate::types::File;
+use futures::stream::{self, Stream};
+use tokio::sync::watch;
+
+/// Progress of a managed download or a tracked upload.
+pub trait TransferProgress: Clone + Send + Sync {
+    /// Result of the successfully finished transfer.
+    type Output;
+
+    /// Error returned by [TransferHandle::wait](crate::client::transfer::TransferHandle::wait) if the transfer stopped being tracked before it finished.
+    const UNTRACKED: Error;
+
+    /// `true` if the transfer won't progress anymore.
+    fn is_finished(&self) -> bool;
+
+    /// Result of the transfer; `None` while it isn't finished.
+    fn result(&self) -> Option<Result<Self::Output>>;
+}
+
+/// Handle of a file transfer, which follows its progress.
+#[derive(Debug, Clone)]
+pub struct TransferHandle<P> {
+    file_id: i32,
+    progress: watch::Receiver<P>,
+}
+
+impl<P> TransferHandle<P>
+where
+    P: TransferProgress,
+{
+    pub(crate) fn new(file_id: i32, progress: watch::Receiver<P>) -> Self {
+        Self { file_id, progress }
+    }
+
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    /// Returns current progress.
+    pub fn progress(&self) -> P {
+        self.progress.borrow().clone()
+    }
+
+    /// Returns receiver notified on every progress change.
+    pub fn progress_receiver(&self) -> watch::Receiver<P> {
+        self.progress.clone()
+    }
+
+    /// Returns stream of progress changes, starting from the current one. The stream ends after the transfer is finished.
+    /// Intermediate changes may be skipped if the stream is polled slower than the file is transferred.
+    pub fn progress_stream(&self) -> impl Stream<Item = P> {
+        let mut receiver = self.progress.clone();
+        receiver.mark_changed();
+        stream::unfold(Some(receiver), |receiver| async move {
+            let mut receiver = receiver?;
+            // error means that the transfer is finished and its last progress is already returned
+            receiver.changed().await.ok()?;
+            let progress = receiver.borrow_and_update().clone();
+            let next = match progress.is_finished() {
+                true => None,
+                false => Some(receiver),
+            };
+            Some((progress, next))
+        })
+    }
+
+    /// Waits until the transfer is finished and returns its result.
+    pub async fn wait(mut self) -> Result<P::Output> {
+        loop {
+            if let Some(result) = self.progress.borrow_and_update().result() {
+                return result;
+            }
+            if self.progress.changed().await.is_err() {
+                return self.progress.borrow().result().unwrap_or(Err(P::UNTRACKED));
+            }
+        }
+    }
+}
+
+/// File size or its expected size; 0 if unknown.
+pub(crate) fn file_size(file: &File) -> i64 {
+    match file.size() {
+        0 => file.expected_size(),
+        size => size,
+    }
+}
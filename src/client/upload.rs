@@ -0,0 +1,459 @@
+//! Tracking of file uploads.
+//!
+//! Upload progress is reported only with [UpdateFile](crate::types::UpdateFile). [UploadTracker](crate::client::upload::UploadTracker)
+//! follows these updates for files started with [Client::upload](crate::client::Client::upload) or passed to [Client::track_upload](crate::client::Client::track_upload),
+//! for example files of messages sent with [InputFileLocal](crate::types::InputFileLocal).
+//! ```no_run
+//! # use futures::StreamExt;
+//! # use rust_tdlib::client::{Client, tdlib_client::TdJson};
+//! # use rust_tdlib::types::{FileType, FileTypeDocument, InputFile, InputFileLocal, UploadFile};
+//! # async fn run(client: Client<TdJson>) -> rust_tdlib::errors::Result<()> {
+//! // the client is built with `.with_upload_tracker(UploadTracker::new())`
+//! let request = UploadFile::builder()
+//!     .file(InputFile::Local(InputFileLocal::builder().path("report.pdf").build()))
+//!     .file_type(FileType::Document(FileTypeDocument::builder().build()))
+//!     .priority(1)
+//!     .build();
+//! let upload = client.upload(request).await?;
+//! let mut progress = Box::pin(upload.progress_stream());
+//! while let Some(progress) = progress.next().await {
+//!     println!("{}/{}", progress.uploaded_size(), progress.size());
+//! }
+//! let uploaded = upload.wait().await?;
+//! println!("{}", uploaded.remote_id());
+//! # Ok(())
+//! # }
+//! ```
+use super::tdlib_client::TdLibClient;
+use super::transfer::{file_size, TransferHandle, TransferProgress};
+use super::Client;
+use crate::errors::{Error, Result};
+use crate::types::{CancelUploadFile, Error as TDLibError, File, UploadFile};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::sync::watch;
+
+// number of recent updates of untracked files, which are kept for uploads tracked after their updates
+const RECENT_FILES: usize = 128;
+const UPLOAD_TRACKER_NOT_SPECIFIED: Error = Error::BadRequest(
+    "upload tracker not specified, need to call `ClientBuilder::with_upload_tracker(...)`",
+);
+const UPLOAD_CANCELLED: Error = Error::Internal("upload cancelled");
+
+/// State of a tracked upload.
+#[derive(Debug, Clone)]
+pub enum UploadStatus {
+    Active,
+    Completed,
+    /// Cancelled with [Client::cancel_upload](crate::client::Client::cancel_upload), or stopped by TDLib if the upload was started with [Client::upload](crate::client::Client::upload).
+    Cancelled,
+    /// TDLib stopped the upload of the message file without error; it may be continued, for example when the message is resent.
+    /// The upload isn't finished and stays tracked until it's completed, failed or cancelled.
+    Stopped,
+    /// Sending of the message with the file failed.
+    Failed(TDLibError),
+}
+
+/// Progress of a tracked upload.
+#[derive(Debug, Clone)]
+pub struct UploadProgress {
+    file_id: i32,
+    status: UploadStatus,
+    uploaded_size: i64,
+    size: i64,
+    remote_id: String,
+    remote_unique_id: String,
+}
+
+impl UploadProgress {
+    fn new(file_id: i32) -> Self {
+        Self {
+            file_id,
+            status: UploadStatus::Active,
+            uploaded_size: 0,
+            size: 0,
+            remote_id: String::new(),
+            remote_unique_id: String::new(),
+        }
+    }
+
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    pub fn status(&self) -> &UploadStatus {
+        &self.status
+    }
+
+    pub fn uploaded_size(&self) -> i64 {
+        self.uploaded_size
+    }
+
+    /// File size or its expected size; 0 if unknown.
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+}
+
+impl TransferProgress for UploadProgress {
+    type Output = UploadedFile;
+
+    const UNTRACKED: Error = UPLOAD_CANCELLED;
+
+    /// `true` if the upload is completed, cancelled or failed.
+    fn is_finished(&self) -> bool {
+        !matches!(self.status, UploadStatus::Active | UploadStatus::Stopped)
+    }
+
+    fn result(&self) -> Option<Result<UploadedFile>> {
+        match &self.status {
+            UploadStatus::Active | UploadStatus::Stopped => None,
+            UploadStatus::Completed => Some(Ok(UploadedFile {
+                file_id: self.file_id,
+                remote_id: self.remote_id.clone(),
+                remote_unique_id: self.remote_unique_id.clone(),
+            })),
+            UploadStatus::Cancelled => Some(Err(UPLOAD_CANCELLED)),
+            UploadStatus::Failed(err) => Some(Err(Error::TDLibError(err.clone()))),
+        }
+    }
+}
+
+/// Identifiers of the uploaded file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadedFile {
+    file_id: i32,
+    remote_id: String,
+    remote_unique_id: String,
+}
+
+impl UploadedFile {
+    pub fn file_id(&self) -> i32 {
+        self.file_id
+    }
+
+    /// Remote identifier of the file, which can be used to send it again with [InputFileRemote](crate::types::InputFileRemote).
+    pub fn remote_id(&self) -> &str {
+        &self.remote_id
+    }
+
+    /// Unique identifier of the file, which is the same for different clients and can't be used to download or reuse the file.
+    pub fn remote_unique_id(&self) -> &str {
+        &self.remote_unique_id
+    }
+}
+
+/// Handle of a tracked upload. [wait](crate::client::transfer::TransferHandle::wait) returns remote identifiers of the file.
+pub type Upload = TransferHandle<UploadProgress>;
+
+#[derive(Debug, Default)]
+struct UploadState {
+    uploads: HashMap<i32, watch::Sender<UploadProgress>>,
+    // uploads started with UploadFile, which can't be continued after they are stopped
+    standalone: HashSet<i32>,
+    // updates may be received before the upload is tracked
+    recent: VecDeque<File>,
+}
+
+impl UploadState {
+    fn update(&mut self, file: &File) {
+        let progress = match self.uploads.get(&file.id()) {
+            None => {
+                if self.recent.len() >= RECENT_FILES {
+                    self.recent.pop_front();
+                }
+                self.recent.push_back(file.clone());
+                return;
+            }
+            Some(progress) => progress,
+        };
+        let remote = file.remote();
+        let cancelled = !remote.is_uploading_completed()
+            && !remote.is_uploading_active()
+            && self.standalone.contains(&file.id());
+        progress.send_modify(|progress| {
+            progress.uploaded_size = remote.uploaded_size();
+            progress.size = file_size(file);
+            progress.remote_id = remote.id().clone();
+            progress.remote_unique_id = remote.unique_id().clone();
+            progress.status = if remote.is_uploading_completed() {
+                UploadStatus::Completed
+            } else if remote.is_uploading_active() {
+                UploadStatus::Active
+            } else if cancelled {
+                UploadStatus::Cancelled
+            } else {
+                UploadStatus::Stopped
+            };
+        });
+        // stopped uploads of messages are kept, because they may be continued or failed later
+        if remote.is_uploading_completed() || cancelled {
+            self.uploads.remove(&file.id());
+            self.standalone.remove(&file.id());
+        }
+    }
+
+    fn finish(&mut self, file_id: i32, status: UploadStatus) -> bool {
+        self.standalone.remove(&file_id);
+        match self.uploads.remove(&file_id) {
+            None => false,
+            Some(progress) => {
+                progress.send_modify(|progress| progress.status = status);
+                true
+            }
+        }
+    }
+}
+
+/// Collects identifiers of all files in the message content.
+fn file_ids(value: &Value, ids: &mut Vec<i32>) {
+    match value {
+        Value::Object(object) => {
+            if object.get("@type").and_then(|t| t.as_str()) == Some("file") {
+                if let Some(id) = object.get("id").and_then(|id| id.as_i64()) {
+                    ids.push(id as i32);
+                }
+            }
+            object.values().for_each(|value| file_ids(value, ids));
+        }
+        Value::Array(values) => values.iter().for_each(|value| file_ids(value, ids)),
+        _ => {}
+    }
+}
+
+fn send_error(update: &Value) -> TDLibError {
+    let (code, message) = match update.get("error") {
+        Some(error) => (error.get("code"), error.get("message")),
+        None => (update.get("error_code"), update.get("error_message")),
+    };
+    TDLibError::builder()
+        .code(code.and_then(|c| c.as_i64()).unwrap_or_default() as i32)
+        .message(message.and_then(|m| m.as_str()).unwrap_or_default())
+        .build()
+}
+
+/// Tracks uploads of a client.
+#[derive(Debug, Clone, Default)]
+pub struct UploadTracker {
+    state: Arc<Mutex<UploadState>>,
+}
+
+impl UploadTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts tracking of the file upload. If the file is already tracked, returns handle of the existing upload.
+    pub fn track(&self, file: &File) -> Upload {
+        self.track_file(file, false)
+    }
+
+    // standalone uploads are finished as soon as TDLib stops them
+    fn track_file(&self, file: &File, standalone: bool) -> Upload {
+        let mut state = self.state.lock().unwrap();
+        let receiver = match state.uploads.get(&file.id()) {
+            Some(progress) => {
+                let receiver = progress.subscribe();
+                if standalone {
+                    state.standalone.insert(file.id());
+                }
+                receiver
+            }
+            None => {
+                let (progress, receiver) = watch::channel(UploadProgress::new(file.id()));
+                state.uploads.insert(file.id(), progress);
+                if standalone {
+                    state.standalone.insert(file.id());
+                }
+                // the file may be older than updates received before it
+                let latest = state
+                    .recent
+                    .iter()
+                    .filter(|recent| recent.id() == file.id())
+                    .chain(Some(file))
+                    .max_by_key(|file| {
+                        let remote = file.remote();
+                        (remote.is_uploading_completed(), remote.uploaded_size())
+                    })
+                    .cloned();
+                state.recent.retain(|recent| recent.id() != file.id());
+                if let Some(latest) = latest {
+                    state.update(&latest);
+                }
+                receiver
+            }
+        };
+        Upload::new(file.id(), receiver)
+    }
+
+    /// Returns progress of the tracked upload.
+    pub fn progress(&self, file_id: i32) -> Option<UploadProgress> {
+        let state = self.state.lock().unwrap();
+        state
+            .uploads
+            .get(&file_id)
+            .map(|progress| progress.borrow().clone())
+    }
+
+    /// Applies raw TDLib update.
+    pub(crate) fn apply(&self, update: &Value) {
+        match update.get("@type").and_then(|t| t.as_str()) {
+            Some("updateFile") => {
+                if let Some(Ok(file)) = update
+                    .get("file")
+                    .cloned()
+                    .map(serde_json::from_value::<File>)
+                {
+                    self.state.lock().unwrap().update(&file);
+                }
+            }
+            Some("updateMessageSendFailed") => {
+                let mut ids = vec![];
+                if let Some(content) = update.get("message").and_then(|m| m.get("content")) {
+                    file_ids(content, &mut ids);
+                }
+                if ids.is_empty() {
+                    return;
+                }
+                let error = send_error(update);
+                let mut state = self.state.lock().unwrap();
+                for id in ids {
+                    state.finish(id, UploadStatus::Failed(error.clone()));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<R> Client<R>
+where
+    R: TdLibClient + Clone,
+{
+    /// Starts upload with [UploadFile](crate::types::UploadFile) and tracks its progress.
+    /// The upload is cancelled if TDLib stops it before it's completed.
+    pub async fn upload<C: AsRef<UploadFile>>(&self, upload_file: C) -> Result<Upload> {
+        let tracker = self.upload_tracker().ok_or(UPLOAD_TRACKER_NOT_SPECIFIED)?;
+        let file = self.upload_file(upload_file).await?;
+        Ok(tracker.track_file(&file, true))
+    }
+
+    /// Tracks upload of the file, which is started by other request, for example by sending a message with a local file.
+    pub fn track_upload(&self, file: &File) -> Result<Upload> {
+        let tracker = self.upload_tracker().ok_or(UPLOAD_TRACKER_NOT_SPECIFIED)?;
+        Ok(tracker.track(file))
+    }
+
+    /// Cancels tracked upload with [CancelUploadFile](crate::types::CancelUploadFile).
+    pub async fn cancel_upload(&self, file_id: i32) -> Result<()> {
+        let tracker = self.upload_tracker().ok_or(UPLOAD_TRACKER_NOT_SPECIFIED)?;
+        let request = CancelUploadFile::builder().file_id(file_id).build();
+        self.cancel_upload_file(request).await?;
+        tracker
+            .state
+            .lock()
+            .unwrap()
+            .finish(file_id, UploadStatus::Cancelled);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{UploadStatus, UploadTracker};
+    use crate::client::mocked_raw_api::MockedRawApi;
+    use crate::client::transfer::TransferProgress;
+    use crate::client::Client;
+    use crate::types::{File, FileType, FileTypeDocument, InputFile, InputFileLocal, UploadFile};
+    use serde_json::{json, Value};
+
+    fn file(file_id: i32, uploaded_size: i64, active: bool, completed: bool) -> Value {
+        json!({
+            "@type": "file",
+            "id": file_id,
+            "size": 100,
+            "local": {"@type": "localFile", "path": "/tmp/file", "is_downloading_completed": true},
+            "remote": {
+                "@type": "remoteFile",
+                "id": if completed { "remote" } else { "" },
+                "unique_id": if completed { "unique" } else { "" },
+                "is_uploading_active": active,
+                "is_uploading_completed": completed,
+                "uploaded_size": uploaded_size,
+            },
+        })
+    }
+
+    #[test]
+    fn track_upload() {
+        let tracker = UploadTracker::new();
+        // update received before the upload is tracked
+        tracker.apply(&json!({"@type": "updateFile", "file": file(1, 50, true, false)}));
+        let upload =
+            tracker.track(&serde_json::from_value::<File>(file(1, 10, true, false)).unwrap());
+        assert_eq!(upload.progress().uploaded_size(), 50);
+
+        tracker.apply(&json!({"@type": "updateFile", "file": file(1, 100, false, true)}));
+        let progress = upload.progress();
+        assert!(matches!(progress.status(), UploadStatus::Completed));
+        let uploaded = progress.result().unwrap().unwrap();
+        assert_eq!(uploaded.remote_id(), "remote");
+        assert_eq!(uploaded.remote_unique_id(), "unique");
+        assert!(tracker.progress(1).is_none());
+    }
+
+    #[test]
+    fn send_failed() {
+        let tracker = UploadTracker::new();
+        let upload =
+            tracker.track(&serde_json::from_value::<File>(file(2, 0, true, false)).unwrap());
+        tracker.apply(&json!({
+            "@type": "updateMessageSendFailed",
+            "message": {"content": {"@type": "messageDocument", "document": {"@type": "document", "document": file(2, 0, false, false)}}},
+            "error_code": 400,
+            "error_message": "FILE_PARTS_INVALID",
+        }));
+        match upload.progress().status() {
+            UploadStatus::Failed(err) => assert_eq!(err.code(), 400),
+            status => panic!("unexpected status {:?}", status),
+        }
+    }
+
+    #[test]
+    fn stopped_upload() {
+        let tracker = UploadTracker::new();
+        let upload =
+            tracker.track(&serde_json::from_value::<File>(file(3, 10, true, false)).unwrap());
+        tracker.apply(&json!({"@type": "updateFile", "file": file(3, 10, false, false)}));
+        let progress = upload.progress();
+        assert!(matches!(progress.status(), UploadStatus::Stopped));
+        assert!(!progress.is_finished());
+        assert!(progress.result().is_none());
+
+        // the upload is continued when the message is resent
+        tracker.apply(&json!({"@type": "updateFile", "file": file(3, 100, false, true)}));
+        assert!(upload.progress().is_finished());
+        assert!(tracker.progress(3).is_none());
+    }
+
+    #[tokio::test]
+    async fn standalone_upload_stopped() {
+        let api = MockedRawApi::new(vec![file(4, 0, true, false)]);
+        let tracker = UploadTracker::new();
+        let client = api.client_with(Client::builder().with_upload_tracker(tracker.clone()));
+        let request = UploadFile::builder()
+            .file(InputFile::Local(
+                InputFileLocal::builder().path("/tmp/file").build(),
+            ))
+            .file_type(FileType::Document(FileTypeDocument::builder().build()))
+            .priority(1)
+            .build();
+        let upload = client.upload(request).await.unwrap();
+        assert_eq!(api.request_types(), vec!["uploadFile"]);
+
+        tracker.apply(&json!({"@type": "updateFile", "file": file(4, 10, false, false)}));
+        assert!(upload.wait().await.is_err());
+        assert!(tracker.progress(4).is_none());
+    }
+}
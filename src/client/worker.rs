@@ -1,34 +1,30 @@
 use super::{
-    auth_handler::{AuthStateHandler, ConsoleAuthStateHandler},
+    auth_handler::{AuthStateHandler, ConsoleAuthStateHandler, WaitPasswordAction},
+    executor::{self, Executor, TaskHandle},
     observer::OBSERVER,
     tdlib_client::{TdJson, TdLibClient},
     {Client, ClientState},
 };
-use crate::types::DisableProxy;
-#[allow(unused_imports)]
 use crate::types::{
-    AddProxy, EnableProxy, GetAuthorizationState, GetProxies, JsonValue, ProxyType,
-    ProxyTypeMtproto, TestProxy,
+    AddProxy, DisableProxy, EnableProxy, GetAuthorizationState, JsonValue, PingProxy,
 };
 use crate::{
     errors::{Error, Result},
     tdjson::ClientId,
     types::{
-        AuthorizationState, CheckAuthenticationCode, CheckAuthenticationPassword,
-        CheckDatabaseEncryptionKey, GetApplicationConfig, RObject, RegisterUser,
-        SetAuthenticationPhoneNumber, SetTdlibParameters, Update, UpdateAuthorizationState,
+        AuthorizationState, AuthorizationStateWaitPassword, CheckAuthenticationCode,
+        CheckAuthenticationPassword, CheckAuthenticationPasswordRecoveryCode,
+        CheckDatabaseEncryptionKey, GetApplicationConfig, RObject, RecoverAuthenticationPassword,
+        RegisterUser, RequestAuthenticationPasswordRecovery, SetAuthenticationPhoneNumber,
+        SetTdlibParameters, Update, UpdateAuthorizationState,
     },
 };
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::Mutex;
-use tokio::{
-    sync::{mpsc, RwLock},
-    task::JoinHandle,
-    time,
-};
+use tokio::sync::{mpsc, Mutex, RwLock};
 
 #[derive(Debug)]
 pub struct WorkerBuilder<A, T>
@@ -38,8 +34,10 @@ where
 {
     read_updates_timeout: f64,
     channels_send_timeout: f64,
+    auth_attempts: usize,
     auth_state_handler: A,
     tdlib_client: T,
+    executor: Option<Arc<dyn Executor>>,
 }
 
 impl Default for WorkerBuilder<ConsoleAuthStateHandler, TdJson> {
@@ -48,8 +46,10 @@ impl Default for WorkerBuilder<ConsoleAuthStateHandler, TdJson> {
         Self {
             read_updates_timeout: 2.0,
             channels_send_timeout: 5.0,
+            auth_attempts: 3,
             auth_state_handler: ConsoleAuthStateHandler::new(),
             tdlib_client: TdJson::new(),
+            executor: executor::default_executor(),
         }
     }
 }
@@ -70,6 +70,13 @@ where
         self
     }
 
+    /// Specifies how many times [AuthStateHandler](crate::client::auth_handler::AuthStateHandler) will be asked for data when TDLib rejects it as invalid (wrong code, password, phone number and so on).
+    /// Default is 3. Values below 1 are treated as 1.
+    pub fn with_auth_attempts(mut self, auth_attempts: usize) -> Self {
+        self.auth_attempts = auth_attempts.max(1);
+        self
+    }
+
     /// [AuthStateHandler](crate::client::client::AuthStateHandler) allows you to handle particular "auth states", such as [WaitPassword](crate::types::AuthorizationStateWaitPassword), [WaitPhoneNumber](crate::types::AuthorizationStateWaitPhoneNumber) and so on.
     /// See [AuthorizationState](crate::types::AuthorizationState).
     pub fn with_auth_state_handler<N>(self, auth_state_handler: N) -> WorkerBuilder<N, T>
@@ -80,10 +87,19 @@ where
             auth_state_handler,
             read_updates_timeout: self.read_updates_timeout,
             channels_send_timeout: self.channels_send_timeout,
+            auth_attempts: self.auth_attempts,
             tdlib_client: self.tdlib_client,
+            executor: self.executor,
         }
     }
 
+    /// [Executor](crate::client::executor::Executor) which will be used to spawn worker tasks and timers.
+    /// [TokioExecutor](crate::client::executor::TokioExecutor) is used by default if `tokio-runtime` feature enabled.
+    pub fn with_executor<E: Executor>(mut self, executor: E) -> Self {
+        self.executor = Some(Arc::new(executor));
+        self
+    }
+
     #[doc(hidden)]
     pub fn with_tdlib_client<C>(self, tdlib_client: C) -> WorkerBuilder<A, C>
     where
@@ -94,15 +110,22 @@ where
             auth_state_handler: self.auth_state_handler,
             read_updates_timeout: self.read_updates_timeout,
             channels_send_timeout: self.channels_send_timeout,
+            auth_attempts: self.auth_attempts,
+            executor: self.executor,
         }
     }
 
     pub fn build(self) -> Result<Worker<A, T>> {
+        let executor = self.executor.ok_or(Error::BadRequest(
+            "executor not specified, need to call `WorkerBuilder::with_executor(...)`",
+        ))?;
         let worker = Worker::new(
             self.auth_state_handler,
             self.read_updates_timeout,
             self.channels_send_timeout,
+            self.auth_attempts,
             self.tdlib_client,
+            executor,
         );
         Ok(worker)
     }
@@ -154,7 +177,9 @@ where
     auth_state_handler: Arc<A>,
     read_updates_timeout: Duration,
     channels_send_timeout: Duration,
+    auth_attempts: usize,
     tdlib_client: S,
+    executor: Arc<dyn Executor>,
     clients: Arc<RwLock<ClientsMap<S>>>,
 }
 
@@ -229,6 +254,8 @@ where
     }
 
     /// Method waits for client state changes.
+    /// Note that all callers share the same queue of state changes, so each change will be received only once.
+    /// Use [Client::state_watcher](crate::client::Client::state_watcher) or [Client::authorized](crate::client::Client::authorized) if multiple tasks have to observe the state.
     /// It differ from [wait_auth_state_change](crate::client::worker::Worker::wait_auth_state_change) by error type: you won't receive (AuthorizationState)[crate::types::authorization_state::AuthorizationState] when error occured.
     /// Method may be useful if client already authorized on, for example, previous application startup.
     pub async fn wait_client_state(&self, client: &Client<T>) -> Result<ClientState> {
@@ -242,85 +269,53 @@ where
         }
     }
 
-    async fn set_proxy(&mut self, client: Client<T>, proxy: Option<AddProxy>) -> Result<()> {
-        if proxy.is_none() {
-            let _ = client.disable_proxy(DisableProxy::builder().build()).await;
-            log::debug!("bind_client proxy is none,and not set");
-            return Ok(());
+    // Enables specified proxy or starts proxy manager of the client.
+    // If there are no proxy and proxy manager, proxy will be disabled.
+    async fn set_proxy(&self, client: &Client<T>, proxy: Option<AddProxy>) -> Result<()> {
+        if let Some(manager) = client.proxy_manager() {
+            if proxy.is_some() {
+                log::warn!("client has proxy manager, so specified proxy ignored");
+            }
+            return manager.start(client, self.executor.clone()).await;
         }
-        let proxy = proxy.unwrap();
+        let proxy = match proxy {
+            None => {
+                client
+                    .disable_proxy(DisableProxy::builder().build())
+                    .await?;
+                return Ok(());
+            }
+            Some(proxy) => proxy,
+        };
 
+        let added = client.add_proxy(proxy).await?;
         log::debug!(
-            "bind_client_proxy_param: {}: {} - {:?}",
-            proxy.server(),
-            proxy.port(),
-            proxy.type_()
+            "proxy added: {}:{} ({:?})",
+            added.server(),
+            added.port(),
+            added.type_()
         );
-
-        log::debug!("--bind_client_before set proxy-------");
-        //-----------only for show old proxies--------------------------
-        match client.get_proxies(GetProxies::builder().build()).await {
-            Ok(v) => {
-                for p in v.proxies() {
-                    log::debug!(
-                        "--bind_client exists proxy: {} {}-------",
-                        p.server(),
-                        p.port()
-                    );
-                }
-            }
-            _ => {
-                log::error!("bind_client_not get proxies ");
-            }
-        }
-
-        //-------------------------------------
-        let p = client.add_proxy(proxy).await.map_err(|e| {
-            log::error!("---bind_client_添加代理失败---{}-", e.to_string());
-            e
-        })?;
-        log::debug!("--bind_client_has_add_proxy-------");
-
-        client
-            .test_proxy(
-                TestProxy::builder()
-                    .server(p.server())
-                    .port(p.port())
-                    .timeout(20_f32)
-                    .dc_id(1_i32)
-                    .type_(p.type_())
-                    .build(),
-            )
+        let latency = client
+            .ping_proxy(PingProxy::builder().proxy_id(added.id()).build())
             .await
-            .map_err(|e| {
+            .inspect_err(|err| {
                 log::error!(
-                    "---bind_client_test_proxy_fail 代理测试失败，代理可能不可用---{}-",
-                    e.to_string()
-                );
-                e
-            })
-            .map(|data| {
-                log::debug!("--bind_client_test_proxy_ok ---{:?}---", data);
-                data
+                    "proxy {}:{} is unavailable: {}",
+                    added.server(),
+                    added.port(),
+                    err
+                )
             })?;
-        log::debug!("--bind_client_after_set_proxy-------");
-        let _ = client
-            .enable_proxy(EnableProxy::builder().proxy_id(p.id()).build())
-            .await
-            .map_err(|e| {
-                log::error!("---bind_client_enabled_proxy_error---{}-", e.to_string());
-                e
-            })
-            .map(|data| {
-                log::debug!("--bind_client_enabled_proxy_ok ---{:?}---", data);
-                data
-            });
-
+        log::debug!("proxy latency: {}s", latency.seconds());
+        client
+            .enable_proxy(EnableProxy::builder().proxy_id(added.id()).build())
+            .await?;
         Ok(())
     }
 
     /// Binds client with worker and runs authorization routines.
-    /// Method returns error if worker is not running or client already bound
+    /// Specified proxy will be checked and enabled; if the client has [ProxyManager](crate::client::proxy::ProxyManager), it will be started instead.
+    /// Method returns error if worker is not running, client already bound or proxy is unavailable
     pub async fn bind_client(
         &mut self,
         mut client: Client<T>,
@@ -332,6 +327,7 @@ where
         let client_id = client.get_tdlib_client().new_client();
         log::debug!("bind_client_new client created: {}", client_id);
         client.set_client_id(client_id)?;
+        client.set_executor(self.executor.clone());
 
         log::debug!("--bind_client_before_--get_auth_state_channel_size-----");
 
@@ -354,12 +350,7 @@ where
 
         self.clients.write().await.insert(client_id, ctx);
         log::debug!("bind_client_new_client_added and insert,and will send_first request");
-        //-----------proxy start--------------------------
-        let _ = self.set_proxy(client.clone(), proxy).await.map_err(|e| {
-            log::error!("---bind_client_set_proxy_error---{}-", e.to_string());
-            e
-        })?;
-        //-----------proxy ok--------------------------
+        self.set_proxy(&client, proxy).await?;
 
         // We need to call any tdlib method to retrieve first response.
         // Otherwise client can't be authorized: no `UpdateAuthorizationState` send by TDLib.
@@ -381,6 +372,7 @@ where
         let client_id = client.get_tdlib_client().new_client();
         // log::debug!("new client created: {}", client_id);
         client.set_client_id(client_id).unwrap();
+        client.set_executor(self.executor.clone());
 
         let (psx, prx) = mpsc::channel::<ClientState>(5);
         let ctx = ClientContext {
@@ -400,7 +392,9 @@ where
         auth_state_handler: A,
         read_updates_timeout: f64,
         channels_send_timeout: f64,
+        auth_attempts: usize,
         tdlib_client: T,
+        executor: Arc<dyn Executor>,
     ) -> Self {
         let run_flag = Arc::new(AtomicBool::new(false));
         let clients: ClientsMap<T> = HashMap::new();
@@ -408,16 +402,18 @@ where
         Self {
             run_flag,
             tdlib_client,
-            read_updates_timeout: time::Duration::from_secs_f64(read_updates_timeout),
-            channels_send_timeout: time::Duration::from_secs_f64(channels_send_timeout),
+            read_updates_timeout: Duration::from_secs_f64(read_updates_timeout),
+            channels_send_timeout: Duration::from_secs_f64(channels_send_timeout),
+            auth_attempts,
+            executor,
             auth_state_handler: Arc::new(auth_state_handler),
             clients: Arc::new(RwLock::new(clients)),
         }
     }
 
     /// Starts interaction with TDLib.
-    /// It returns [JoinHandle](tokio::task::JoinHandle) which allows you to handle worker state: if it yields - so worker is definitely stopped.
-    pub fn start(&mut self) -> JoinHandle<()> {
+    /// It returns [TaskHandle](crate::client::executor::TaskHandle) which allows you to handle worker state: if it yields - so worker is definitely stopped.
+    pub fn start(&mut self) -> TaskHandle {
         let (auth_sx, auth_rx) = mpsc::channel::<UpdateAuthorizationState>(20);
 
         self.run_flag.store(true, Ordering::Release);
@@ -426,7 +422,7 @@ where
 
         let run_flag = self.run_flag.clone();
 
-        tokio::spawn(async move {
+        executor::spawn(self.executor.as_ref(), async move {
             tokio::select! {
                 _ = auth_handle => {
                     log::debug!("authorization task stopped");
@@ -440,30 +436,44 @@ where
     }
 
     /// Stops the client.
-    /// You may want to await JoinHandle retrieved with `client.start().await` after calling `stop`.
+    /// You may want to await TaskHandle retrieved with `client.start().await` after calling `stop`.
     pub fn stop(&self) {
         self.run_flag.store(false, Ordering::Release);
     }
 
     // It's the base routine: sends received updates to particular handlers: observer or auth_state handler
-    fn init_updates_task(&self, auth_sx: mpsc::Sender<UpdateAuthorizationState>) -> JoinHandle<()> {
+    fn init_updates_task(&self, auth_sx: mpsc::Sender<UpdateAuthorizationState>) -> TaskHandle {
         let run_flag = self.run_flag.clone();
         let clients = self.clients.clone();
         let recv_timeout = self.read_updates_timeout;
         let send_timeout = self.channels_send_timeout;
         let tdlib_client = Arc::new(self.tdlib_client.clone());
+        let executor = self.executor.clone();
 
-        tokio::spawn(async move {
-            let current = tokio::runtime::Handle::try_current().unwrap();
+        executor::spawn(self.executor.as_ref(), async move {
             while run_flag.load(Ordering::Acquire) {
                 let cl = tdlib_client.clone();
-                if let Some(json) = current
-                    .spawn_blocking(move || cl.receive(recv_timeout.as_secs_f64()))
-                    .await
-                    .unwrap()
-                {
-                    log::debug!("received_raw_json: {}", json.as_str());
-                    handle_td_resp_received(json.as_str(), &auth_sx, &clients, send_timeout).await;
+                let received = executor::spawn_blocking(executor.as_ref(), move || {
+                    cl.receive(recv_timeout.as_secs_f64())
+                })
+                .await;
+                match received {
+                    Ok(Some(json)) => {
+                        log::debug!("received_raw_json: {}", json.as_str());
+                        handle_td_resp_received(
+                            json.as_str(),
+                            &auth_sx,
+                            &clients,
+                            executor.as_ref(),
+                            send_timeout,
+                        )
+                        .await;
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        log::error!("can't receive updates: {}", err);
+                        return;
+                    }
                 }
             }
         })
@@ -479,12 +489,12 @@ where
             None => Err(Error::BadRequest("client not bound yet")),
             Some(ctx) => {
                 handle_auth_state(
-                    client,
-                    ctx.pub_state_message_sender(),
-                    ctx.private_state_message_sender(),
+                    ctx,
                     self.auth_state_handler.as_ref(),
                     auth_state,
+                    self.executor.as_ref(),
                     self.channels_send_timeout,
+                    self.auth_attempts,
                 )
                 .await
             }
@@ -492,15 +502,14 @@ where
     }
 
     // created task handles [UpdateAuthorizationState][crate::types::UpdateAuthorizationState] and sends it to particular methods of specified [AuthStateHandler](crate::client::client::AuthStateHandler)
-    fn init_auth_task(
-        &self,
-        mut auth_rx: mpsc::Receiver<UpdateAuthorizationState>,
-    ) -> JoinHandle<()> {
+    fn init_auth_task(&self, mut auth_rx: mpsc::Receiver<UpdateAuthorizationState>) -> TaskHandle {
         let auth_state_handler = self.auth_state_handler.clone();
         let clients = self.clients.clone();
         let send_timeout = self.channels_send_timeout;
+        let auth_attempts = self.auth_attempts;
+        let executor = self.executor.clone();
 
-        tokio::spawn(async move {
+        executor::spawn(self.executor.as_ref(), async move {
             while let Some(auth_state) = auth_rx.recv().await {
                 log::debug!("received new auth state: {:?}", auth_state);
                 if let Some(client_id) = auth_state.client_id() {
@@ -510,13 +519,17 @@ where
                             continue;
                         }
                         Some(client_ctx) => {
+                            client_ctx
+                                .client()
+                                .status_sender()
+                                .set_authorization_state(auth_state.authorization_state().clone());
                             handle_auth_state(
-                                client_ctx.client(),
-                                client_ctx.pub_state_message_sender(),
-                                client_ctx.private_state_message_sender(),
+                                client_ctx,
                                 auth_state_handler.as_ref(),
                                 auth_state.authorization_state(),
+                                executor.as_ref(),
                                 send_timeout,
+                                auth_attempts,
                             )
                             .await
                         }
@@ -527,21 +540,31 @@ where
                             // log::debug!("state changes handled properly")
                         }
                         Err(err) => {
+                            log::error!(
+                                "can't handle {:?} for client {}: {}",
+                                auth_state.authorization_state(),
+                                client_id,
+                                err
+                            );
                             match clients.read().await.get(&client_id) {
                                 None => {
                                     log::error!("client not found")
                                 }
                                 Some(cl) => match cl.pub_state_message_sender() {
                                     Some(state_sender) => {
-                                        if let Err(err) = state_sender
-                                            .send_timeout(Err((err, auth_state)), send_timeout)
-                                            .await
+                                        if let Err(err) = executor::send_timeout(
+                                            executor.as_ref(),
+                                            state_sender,
+                                            Err((err, auth_state)),
+                                            send_timeout,
+                                        )
+                                        .await
                                         {
                                             log::error!("cannot send client state changes: {}", err)
                                         }
                                     }
                                     None => {
-                                        log::error!("error received and possibly cannot be handled because of empty state receiver: {err}")
+                                        log::warn!("error can't be reported because of empty state receiver, see `Client::builder().with_auth_state_channel(...)`")
                                     }
                                 },
                             };
@@ -553,10 +576,11 @@ where
     }
 }
 
-async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone>(
+async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone + 'static>(
     response: &str,
     auth_sx: &mpsc::Sender<UpdateAuthorizationState>,
     clients: &RwLock<ClientsMap<S>>,
+    executor: &dyn Executor,
     send_timeout: Duration,
 ) {
     match serde_json::from_str::<serde_json::Value>(response) {
@@ -566,14 +590,21 @@ async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone>(
         ),
         Ok(t) => {
             if let Some(t) = OBSERVER.notify(t) {
-                match serde_json::from_value::<Update>(t) {
+                match Update::deserialize(&t) {
                     Err(err) => {
                         log::error!("author_raw_json_error_cannot deserialize to update: {err:?}, raw_json_data: {response:?}")
                     }
                     Ok(update) => {
                         if let Update::AuthorizationState(auth_state) = update {
                             log::trace!("auth state send: {:?}", auth_state);
-                            match auth_sx.send_timeout(auth_state, send_timeout).await {
+                            match executor::send_timeout(
+                                executor,
+                                auth_sx,
+                                auth_state,
+                                send_timeout,
+                            )
+                            .await
+                            {
                                 Ok(_) => {
                                     log::trace!("auth state sent");
                                 }
@@ -590,11 +621,43 @@ async fn handle_td_resp_received<S: TdLibClient + Send + Sync + Clone>(
                                     )
                                 }
                                 Some(ctx) => {
+                                    if let Some(cache) = ctx.client().request_cache() {
+                                        cache.invalidate(&t);
+                                    }
+                                    ctx.client().options().apply(&t);
+                                    if let Some(store) = ctx.client().store() {
+                                        store.apply(&t);
+                                    }
+                                    if let Some(chat_lists) = ctx.client().chat_lists() {
+                                        chat_lists.apply(&t);
+                                    }
+                                    if let Some(message_cache) = ctx.client().message_cache() {
+                                        message_cache.apply(&t);
+                                    }
+                                    if let Some(download_manager) = ctx.client().download_manager()
+                                    {
+                                        download_manager.apply(&t);
+                                    }
+                                    if let Some(upload_tracker) = ctx.client().upload_tracker() {
+                                        upload_tracker.apply(&t);
+                                    }
+                                    if let Some(file_generators) = ctx.client().file_generators() {
+                                        file_generators.handle(ctx.client(), &update, executor);
+                                    }
+                                    if let Update::ConnectionState(connection_state) = &update {
+                                        ctx.client()
+                                            .status_sender()
+                                            .set_connection_state(connection_state.state().clone());
+                                    }
                                     if let Some(sender) = ctx.client().updates_sender() {
                                         log::trace!("sending update to client");
-                                        match sender
-                                            .send_timeout(Box::new(update), send_timeout)
-                                            .await
+                                        match executor::send_timeout(
+                                            executor,
+                                            sender,
+                                            Box::new(update),
+                                            send_timeout,
+                                        )
+                                        .await
                                         {
                                             Ok(_) => {
                                                 log::trace!("update sent");
@@ -625,13 +688,14 @@ where
 }
 
 async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
-    client: &Client<R>,
-    pub_state_sender: &Option<mpsc::Sender<StateMessage>>,
-    private_state_sender: &mpsc::Sender<ClientState>,
+    ctx: &ClientContext<R>,
     auth_state_handler: &A,
     state: &AuthorizationState,
-    send_state_timeout: time::Duration,
+    executor: &dyn Executor,
+    send_state_timeout: Duration,
+    auth_attempts: usize,
 ) -> Result<()> {
+    let client = ctx.client();
     // log::debug!("handling new auth state: {:?}", state);
     let mut result_state = None;
     let res = match state {
@@ -647,18 +711,129 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
             result_state = Some(ClientState::Opened);
             Ok(())
         }
+        AuthorizationState::WaitOtherDeviceConfirmation(wait_device_confirmation) => {
+            // log::debug!("handling other device confirmation");
+            auth_state_handler
+                .handle_other_device_confirmation(wait_device_confirmation)
+                .await;
+            // log::debug!("handled other device confirmation");
+            Ok(())
+        }
+        AuthorizationState::WaitCode(_)
+        | AuthorizationState::WaitEncryptionKey(_)
+        | AuthorizationState::WaitPassword(_)
+        | AuthorizationState::WaitPhoneNumber(_)
+        | AuthorizationState::WaitRegistration(_) => {
+            handle_auth_input(client, auth_state_handler, state, auth_attempts).await
+        }
+        AuthorizationState::WaitTdlibParameters(_) => {
+            log::debug!("going to set tdlib_parameters");
+            client
+                .set_tdlib_parameters(
+                    SetTdlibParameters::builder()
+                        .parameters(client.tdlib_parameters())
+                        .build(),
+                )
+                .await?;
+            log::debug!("tdlib parameters set");
+            Ok(())
+        }
+        AuthorizationState::GetAuthorizationState(_) => Err(Error::Internal(
+            "retrieved GetAuthorizationState update but observer not found any subscriber",
+        )),
+    };
+
+    match &result_state {
+        None => {}
+        Some(state) => {
+            if let Err(err) = ctx.private_state_message_sender().send(state.clone()).await {
+                {
+                    log::error!(
+                        "can't send state update, but state changed; error: {:?}, state: {:?}",
+                        err,
+                        state
+                    )
+                };
+            }
+
+            if let Some(sender) = ctx.pub_state_message_sender() {
+                if let Err(err) =
+                    executor::send_timeout(executor, sender, Ok(state.clone()), send_state_timeout)
+                        .await
+                {
+                    log::error!(
+                        "can't send state update, but state changed; error: {:?}, state: {:?}",
+                        err,
+                        state
+                    )
+                };
+            }
+        }
+    }
+    res
+}
+
+// Asks auth state handler for data required by the state and passes it to TDLib.
+// If TDLib rejects the data as invalid, handler will be asked again, up to `auth_attempts` times.
+// Password recovery shares the attempts: recovery code is requested once and only the code is asked again.
+async fn handle_auth_input<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
+    client: &Client<R>,
+    auth_state_handler: &A,
+    state: &AuthorizationState,
+    auth_attempts: usize,
+) -> Result<()> {
+    let mut recovering = false;
+    let mut attempt = 1;
+    let recovery_code = loop {
+        let err = match send_auth_input(client, auth_state_handler, state, &mut recovering).await {
+            Ok(recovery_code) => break recovery_code,
+            Err(err) => err,
+        };
+        let retry = attempt < auth_attempts && is_retryable_auth_error(&err);
+        // handler is notified about every rejected input, including the last one
+        if !auth_state_handler
+            .handle_auth_error(state, &err, attempt)
+            .await
+            || !retry
+        {
+            return Err(err);
+        }
+        attempt += 1;
+    };
+    match (state, recovery_code) {
+        (AuthorizationState::WaitPassword(wait_password), Some(recovery_code)) => {
+            // checked recovery code is used once: failed recovery isn't retried, otherwise a new code would be sent
+            let result =
+                recover_password(client, auth_state_handler, wait_password, recovery_code).await;
+            if let Err(err) = &result {
+                auth_state_handler
+                    .handle_auth_error(state, err, attempt)
+                    .await;
+            }
+            result
+        }
+        _ => Ok(()),
+    }
+}
+
+// Passes data returned by the handler to TDLib. Returns checked recovery code if password recovery is chosen.
+async fn send_auth_input<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
+    client: &Client<R>,
+    auth_state_handler: &A,
+    state: &AuthorizationState,
+    recovering: &mut bool,
+) -> Result<Option<String>> {
+    match state {
         AuthorizationState::WaitCode(wait_code) => {
             let code = auth_state_handler.handle_wait_code(wait_code).await;
             client
                 .check_authentication_code(CheckAuthenticationCode::builder().code(code).build())
                 .await?;
-            Ok(())
         }
         AuthorizationState::WaitEncryptionKey(wait_encryption_key) => {
             let key = auth_state_handler
                 .handle_encryption_key(wait_encryption_key)
                 .await;
-            // log::debug!("checking encryption key");
             client
                 .check_database_encryption_key(
                     CheckDatabaseEncryptionKey::builder()
@@ -666,29 +841,40 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
                         .build(),
                 )
                 .await?;
-            // log::debug!("encryption key check done");
-            Ok(())
-        }
-        AuthorizationState::WaitOtherDeviceConfirmation(wait_device_confirmation) => {
-            // log::debug!("handling other device confirmation");
-            auth_state_handler
-                .handle_other_device_confirmation(wait_device_confirmation)
-                .await;
-            // log::debug!("handled other device confirmation");
-            Ok(())
         }
         AuthorizationState::WaitPassword(wait_password) => {
-            let password = auth_state_handler.handle_wait_password(wait_password).await;
-            // log::debug!("checking password");
+            if !*recovering {
+                match auth_state_handler
+                    .handle_wait_password_action(wait_password)
+                    .await
+                {
+                    WaitPasswordAction::Password(password) => {
+                        client
+                            .check_authentication_password(
+                                CheckAuthenticationPassword::builder()
+                                    .password(password)
+                                    .build(),
+                            )
+                            .await?;
+                        return Ok(None);
+                    }
+                    WaitPasswordAction::Recover => {
+                        request_password_recovery(client, wait_password).await?;
+                        *recovering = true;
+                    }
+                }
+            }
+            let recovery_code = auth_state_handler
+                .handle_password_recovery_code(wait_password)
+                .await;
             client
-                .check_authentication_password(
-                    CheckAuthenticationPassword::builder()
-                        .password(password)
+                .check_authentication_password_recovery_code(
+                    CheckAuthenticationPasswordRecoveryCode::builder()
+                        .recovery_code(&recovery_code)
                         .build(),
                 )
                 .await?;
-            // log::debug!("password checked");
-            Ok(())
+            return Ok(Some(recovery_code));
         }
         AuthorizationState::WaitPhoneNumber(wait_phone_number) => {
             let phone_number = auth_state_handler
@@ -701,7 +887,6 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
                         .build(),
                 )
                 .await?;
-            Ok(())
         }
         AuthorizationState::WaitRegistration(wait_registration) => {
             log::debug!("handling wait registration");
@@ -714,66 +899,58 @@ async fn handle_auth_state<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
                 .build();
             client.register_user(register).await?;
             log::debug!("handled register user");
-            Ok(())
-        }
-        AuthorizationState::WaitTdlibParameters(_) => {
-            log::debug!("going to set tdlib_parameters");
-            log::debug!(
-                "--params_of_client: {:#?}-------",
-                client.tdlib_parameters()
-            );
-
-            let _ = client
-                .set_tdlib_parameters(
-                    SetTdlibParameters::builder()
-                        .parameters(client.tdlib_parameters())
-                        .build(),
-                )
-                .await
-                .map_err(|e| {
-                    log::error!("---set_param_error---{}-", e.to_string());
-                    e
-                })
-                .map(|data| {
-                    log::debug!("--set_param_ok ---{:?}---", data);
-                    data
-                });
-            log::debug!("tdlib parameters set");
-            Ok(())
         }
-        AuthorizationState::GetAuthorizationState(_) => Err(Error::Internal(
-            "retrieved GetAuthorizationState update but observer not found any subscriber",
-        )),
+        _ => {}
     };
+    Ok(None)
+}
 
-    match &result_state {
-        None => {}
-        Some(state) => {
-            if let Err(err) = private_state_sender.send(state.clone()).await {
-                {
-                    log::error!(
-                        "can't send state update, but state changed; error: {:?}, state: {:?}",
-                        err,
-                        state
-                    )
-                };
-            }
-
-            if let Some(sender) = &pub_state_sender {
-                if let Err(err) = sender
-                    .send_timeout(Ok(state.clone()), send_state_timeout)
-                    .await
-                {
-                    log::error!(
-                        "can't send state update, but state changed; error: {:?}, state: {:?}",
-                        err,
-                        state
-                    )
-                };
-            }
-        }
+// Requests recovery code, which will be sent to the recovery email address.
+async fn request_password_recovery<R: TdLibClient + Clone>(
+    client: &Client<R>,
+    wait_password: &AuthorizationStateWaitPassword,
+) -> Result<()> {
+    if !wait_password.has_recovery_email_address() {
+        return Err(Error::BadRequest(
+            "password recovery requested, but recovery email address is not set",
+        ));
     }
-    res
+    client
+        .request_authentication_password_recovery(
+            RequestAuthenticationPasswordRecovery::builder().build(),
+        )
+        .await?;
+    log::debug!(
+        "password recovery code sent to {}",
+        wait_password.recovery_email_address_pattern()
+    );
+    Ok(())
+}
+
+// Recovers password with checked recovery code and sets new password.
+async fn recover_password<A: AuthStateHandler + Sync, R: TdLibClient + Clone>(
+    client: &Client<R>,
+    auth_state_handler: &A,
+    wait_password: &AuthorizationStateWaitPassword,
+    recovery_code: String,
+) -> Result<()> {
+    let (new_password, new_hint) = auth_state_handler.handle_new_password(wait_password).await;
+    client
+        .recover_authentication_password(
+            RecoverAuthenticationPassword::builder()
+                .recovery_code(recovery_code)
+                .new_password(new_password)
+                .new_hint(new_hint)
+                .build(),
+        )
+        .await?;
+    Ok(())
+}
+
+// TDLib responds with 400 code when it receives invalid input: wrong code, password, phone number and so on.
+// Other errors (network issues, flood limits, closed client) can't be fixed by asking for the data again.
+fn is_retryable_auth_error(err: &Error) -> bool {
+    matches!(err, Error::TDLibError(err) if err.code() == 400)
 }
 
 async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: ClientId) {
@@ -807,6 +984,248 @@ async fn first_internal_request<S: TdLibClient>(tdlib_client: &S, client_id: Cli
     };
 }
 
+#[cfg(test)]
+mod tests {
+    use super::{handle_auth_input, is_retryable_auth_error};
+    use crate::client::auth_handler::{AuthStateHandler, WaitPasswordAction};
+    use crate::client::mocked_raw_api::{error, ok, MockedRawApi};
+    use crate::errors::Error;
+    use crate::types::{
+        AuthorizationState, AuthorizationStateWaitCode, AuthorizationStateWaitEncryptionKey,
+        AuthorizationStateWaitPassword, AuthorizationStateWaitPhoneNumber,
+        AuthorizationStateWaitRegistration, Error as TDLibError,
+    };
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    #[derive(Debug, Default)]
+    struct TestAuthHandler {
+        // attempts reported with `handle_auth_error`
+        failed: Mutex<Vec<usize>>,
+        stop_retrying: bool,
+        recover: bool,
+    }
+
+    #[async_trait]
+    impl AuthStateHandler for TestAuthHandler {
+        async fn handle_wait_code(&self, _: &AuthorizationStateWaitCode) -> String {
+            "code".to_string()
+        }
+
+        async fn handle_encryption_key(&self, _: &AuthorizationStateWaitEncryptionKey) -> String {
+            "key".to_string()
+        }
+
+        async fn handle_wait_password(&self, _: &AuthorizationStateWaitPassword) -> String {
+            "password".to_string()
+        }
+
+        async fn handle_wait_password_action(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> WaitPasswordAction {
+            match self.recover {
+                true => WaitPasswordAction::Recover,
+                false => WaitPasswordAction::Password("password".to_string()),
+            }
+        }
+
+        async fn handle_password_recovery_code(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> String {
+            "recovery code".to_string()
+        }
+
+        async fn handle_new_password(
+            &self,
+            _: &AuthorizationStateWaitPassword,
+        ) -> (String, String) {
+            ("new password".to_string(), "hint".to_string())
+        }
+
+        async fn handle_wait_phone_number(&self, _: &AuthorizationStateWaitPhoneNumber) -> String {
+            "phone".to_string()
+        }
+
+        async fn handle_wait_registration(
+            &self,
+            _: &AuthorizationStateWaitRegistration,
+        ) -> (String, String) {
+            ("first".to_string(), "last".to_string())
+        }
+
+        async fn handle_auth_error(
+            &self,
+            _: &AuthorizationState,
+            _: &Error,
+            attempt: usize,
+        ) -> bool {
+            self.failed.lock().unwrap().push(attempt);
+            !self.stop_retrying
+        }
+    }
+
+    fn wait_phone_number() -> AuthorizationState {
+        AuthorizationState::WaitPhoneNumber(AuthorizationStateWaitPhoneNumber::builder().build())
+    }
+
+    fn wait_password(has_recovery_email_address: bool) -> AuthorizationState {
+        AuthorizationState::WaitPassword(
+            AuthorizationStateWaitPassword::builder()
+                .has_recovery_email_address(has_recovery_email_address)
+                .build(),
+        )
+    }
+
+    fn tdlib_error(code: i32) -> Error {
+        Error::TDLibError(TDLibError::builder().code(code).message("error").build())
+    }
+
+    #[test]
+    fn retryable_auth_errors() {
+        assert!(is_retryable_auth_error(&tdlib_error(400)));
+        assert!(!is_retryable_auth_error(&tdlib_error(429)));
+        assert!(!is_retryable_auth_error(&tdlib_error(500)));
+        assert!(!is_retryable_auth_error(&Error::Internal("error")));
+    }
+
+    #[tokio::test]
+    async fn auth_input_retried() {
+        let api = MockedRawApi::new(vec![error(400), error(400), ok()]);
+        let handler = TestAuthHandler::default();
+        handle_auth_input(&api.client(), &handler, &wait_phone_number(), 3)
+            .await
+            .unwrap();
+        assert_eq!(api.request_types(), vec!["setAuthenticationPhoneNumber"; 3]);
+        assert_eq!(*handler.failed.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn last_attempt_reported() {
+        let api = MockedRawApi::new(vec![error(400), error(400), ok()]);
+        let handler = TestAuthHandler::default();
+        assert!(
+            handle_auth_input(&api.client(), &handler, &wait_phone_number(), 2)
+                .await
+                .is_err()
+        );
+        assert_eq!(api.request_types(), vec!["setAuthenticationPhoneNumber"; 2]);
+        assert_eq!(*handler.failed.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn auth_input_not_retried() {
+        // error which can't be fixed by another input
+        let api = MockedRawApi::new(vec![error(500), ok()]);
+        let handler = TestAuthHandler::default();
+        assert!(
+            handle_auth_input(&api.client(), &handler, &wait_phone_number(), 3)
+                .await
+                .is_err()
+        );
+        assert_eq!(api.request_types(), vec!["setAuthenticationPhoneNumber"; 1]);
+        assert_eq!(*handler.failed.lock().unwrap(), vec![1]);
+
+        // handler stops retrying
+        let api = MockedRawApi::new(vec![error(400), ok()]);
+        let handler = TestAuthHandler {
+            stop_retrying: true,
+            ..Default::default()
+        };
+        assert!(
+            handle_auth_input(&api.client(), &handler, &wait_phone_number(), 3)
+                .await
+                .is_err()
+        );
+        assert_eq!(api.request_types(), vec!["setAuthenticationPhoneNumber"; 1]);
+    }
+
+    #[tokio::test]
+    async fn password_recovered() {
+        let api = MockedRawApi::new(vec![ok(), error(400), ok(), ok()]);
+        let handler = TestAuthHandler {
+            recover: true,
+            ..Default::default()
+        };
+        handle_auth_input(&api.client(), &handler, &wait_password(true), 3)
+            .await
+            .unwrap();
+        // recovery code is requested once, invalid code is asked again
+        assert_eq!(
+            api.request_types(),
+            vec![
+                "requestAuthenticationPasswordRecovery",
+                "checkAuthenticationPasswordRecoveryCode",
+                "checkAuthenticationPasswordRecoveryCode",
+                "recoverAuthenticationPassword",
+            ]
+        );
+        assert_eq!(api.requests()[3]["new_password"], "new password");
+        assert_eq!(*handler.failed.lock().unwrap(), vec![1]);
+    }
+
+    #[tokio::test]
+    async fn password_recovery_attempts_shared() {
+        let api = MockedRawApi::new(vec![ok(), error(400), error(400), ok()]);
+        let handler = TestAuthHandler {
+            recover: true,
+            ..Default::default()
+        };
+        assert!(
+            handle_auth_input(&api.client(), &handler, &wait_password(true), 2)
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            api.request_types(),
+            vec![
+                "requestAuthenticationPasswordRecovery",
+                "checkAuthenticationPasswordRecoveryCode",
+                "checkAuthenticationPasswordRecoveryCode",
+            ]
+        );
+        assert_eq!(*handler.failed.lock().unwrap(), vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn password_recovery_failed() {
+        // rejected new password isn't retried, otherwise another recovery code would be sent
+        let api = MockedRawApi::new(vec![ok(), ok(), error(400), ok()]);
+        let handler = TestAuthHandler {
+            recover: true,
+            ..Default::default()
+        };
+        assert!(
+            handle_auth_input(&api.client(), &handler, &wait_password(true), 3)
+                .await
+                .is_err()
+        );
+        assert_eq!(
+            api.request_types(),
+            vec![
+                "requestAuthenticationPasswordRecovery",
+                "checkAuthenticationPasswordRecoveryCode",
+                "recoverAuthenticationPassword",
+            ]
+        );
+        assert_eq!(*handler.failed.lock().unwrap(), vec![1]);
+
+        // recovery email address isn't set
+        let api = MockedRawApi::new(vec![ok()]);
+        let handler = TestAuthHandler {
+            recover: true,
+            ..Default::default()
+        };
+        assert!(
+            handle_auth_input(&api.client(), &handler, &wait_password(false), 3)
+                .await
+                .is_err()
+        );
+        assert!(api.requests().is_empty());
+    }
+}
+
 // #[cfg(test)]
 // mod tests {
 //     use crate::client::tdlib_client::TdLibClient;
@@ -6,6 +6,8 @@ pub type TDLibError = crate::types::Error;
 pub enum Error {
     Io(io::Error),
     SerdeJson(serde_json::Error),
+    BsonSer(bson::ser::Error),
+    BsonDe(bson::de::Error),
     TDLibError(TDLibError),
     Internal(&'static str),
     //
@@ -31,6 +33,12 @@ impl fmt::Display for Error {
             Error::SerdeJson(err) => {
                 write!(f, "{}", err)
             }
+            Error::BsonSer(err) => {
+                write!(f, "{}", err)
+            }
+            Error::BsonDe(err) => {
+                write!(f, "{}", err)
+            }
             Error::TDLibError(err) => {
                 write!(f, "{:?}", err)
             }
@@ -53,6 +61,8 @@ impl error::Error for Error {
         match self {
             Error::Io(ref err) => Some(err),
             Error::SerdeJson(ref err) => Some(err),
+            Error::BsonSer(ref err) => Some(err),
+            Error::BsonDe(ref err) => Some(err),
             Error::Internal(_) => None,
             Error::RawStr(_) => None,
             Error::TDLibError(_) => None,
@@ -73,10 +83,22 @@ impl From<serde_json::Error> for Error {
     }
 }
 
-const CLOSED_CHANNEL_ERROR: Error = Error::Internal("channel closed");
-const SEND_TO_CHANNEL_TIMEOUT: Error = Error::Internal("timeout for mpsc occurred");
+impl From<bson::ser::Error> for Error {
+    fn from(err: bson::ser::Error) -> Error {
+        Error::BsonSer(err)
+    }
+}
+
+impl From<bson::de::Error> for Error {
+    fn from(err: bson::de::Error) -> Error {
+        Error::BsonDe(err)
+    }
+}
+
+pub(crate) const CLOSED_CHANNEL_ERROR: Error = Error::Internal("channel closed");
+pub(crate) const SEND_TO_CHANNEL_TIMEOUT: Error = Error::Internal("timeout for mpsc occurred");
 
-#[cfg(feature = "client")]
+#[cfg(feature = "tokio-runtime")]
 impl<T> From<tokio::sync::mpsc::error::SendTimeoutError<T>> for Error {
     fn from(err: tokio::sync::mpsc::error::SendTimeoutError<T>) -> Self {
         match err {
@@ -5,12 +5,18 @@ use std::os::raw::{c_char, c_double, c_int, c_long};
 use std::ptr;
 
 pub type ClientId = i32;
+/// Callback for TDLib log messages, receives verbosity level of the message and null-terminated message text.
+pub type LogMessageCallback = extern "C" fn(verbosity_level: c_int, message: *const c_char);
 #[link(name = "tdjson")]
 extern "C" {
     fn td_create_client_id() -> c_int;
     fn td_send(client_id: c_int, request: *const c_char);
     fn td_receive(timeout: c_double) -> *const c_char;
     fn td_execute(request: *const c_char) -> *const c_char;
+    fn td_set_log_message_callback(
+        max_verbosity_level: c_int,
+        callback: Option<LogMessageCallback>,
+    );
 
     // Deprecated. Use setLogVerbosityLevel request instead.
     fn td_set_log_verbosity_level(level: c_int);
@@ -47,6 +53,12 @@ pub fn receive(timeout: f64) -> Option<String> {
     }
 }
 
+/// Sets the callback that will be called when a message is added to the internal TDLib log.
+/// None of the TDLib methods can be called from the callback. Pass `None` to remove the callback.
+pub fn set_log_message_callback(max_verbosity_level: i32, callback: Option<LogMessageCallback>) {
+    unsafe { td_set_log_message_callback(max_verbosity_level, callback) };
+}
+
 // Deprecated. Use setLogVerbosityLevel request instead.
 pub fn set_log_verbosity_level(level: i32) {
     unsafe { td_set_log_verbosity_level(level) };
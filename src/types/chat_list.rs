@@ -304,6 +304,10 @@ impl ChatListFolder {
 
         ChatListFolderBuilder { inner }
     }
+
+    pub fn chat_folder_id(&self) -> i32 {
+        self.chat_folder_id
+    }
 }
 
 #[doc(hidden)]
@@ -19,7 +19,7 @@ pub struct FinishFileGeneration {
     #[serde(default)]
     generation_id: i64,
     /// If passed, the file generation has failed and must be terminated; pass null if the file generation succeeded
-    error: Error,
+    error: Option<Error>,
 
     #[serde(rename(serialize = "@type"))]
     td_type: String,
@@ -55,7 +55,7 @@ impl FinishFileGeneration {
         self.generation_id
     }
 
-    pub fn error(&self) -> &Error {
+    pub fn error(&self) -> &Option<Error> {
         &self.error
     }
 }
@@ -79,7 +79,7 @@ impl FinishFileGenerationBuilder {
     }
 
     pub fn error<T: AsRef<Error>>(&mut self, error: T) -> &mut Self {
-        self.inner.error = error.as_ref().clone();
+        self.inner.error = Some(error.as_ref().clone());
         self
     }
 }